@@ -1,8 +1,8 @@
 use localize_it::init_locale;
 use std::io::{stdin, stdout, Write};
 
-// Define available locales and enable built-in storage
-init_locale!(En, Ru, storage = true);
+// Define available locales, enable built-in storage and environment detection
+init_locale!(En, Ru, storage = true, env = true);
 
 // Define localized expressions (can be any compile-time type)
 expressions!(
@@ -14,9 +14,9 @@ expressions!(
         En: "Please, enter your name: ",
         Ru: "Пожалуйста, введите ваше имя: ",
     },
-    HELLO: fn(&str) -> String => {
-        En: |name: &str| format!("Hello, {name}!"),
-        Ru: |name: &str| format!("Привет, {name}!"),
+    HELLO: fmt(name: &str) => {
+        En: "Hello, {name}!",
+        Ru: "Привет, {name}!",
     },
 );
 
@@ -30,19 +30,21 @@ fn input() -> String {
 }
 
 fn main() {
-    // Explicitly use a specific locale
-    print!("{}", localize!(ENTER_LANGUAGE, Locale::En));
+    // Detect the locale from `LANG` and similar variables, falls back to default (En)
+    storage::init_from_env();
+
+    // Use the locale from storage
+    print!("{}", localize!(ENTER_LANGUAGE));
 
     let lang = input();
 
-    // Set the locale in storage, falls back to default (En)
-    storage::set_from_caseless_str_or_default(&lang);
+    // Change the locale in storage, keeps the detected one if the input is invalid
+    let _ = storage::set_from_caseless_str(&lang);
 
-    // Use the locale from storage
     print!("{}", localize!(ENTER_YOUR_NAME));
 
     let name = input();
 
-    // Use a callable expression
-    println!("{}", localize!(HELLO => [&name]));
+    // Use a template with named arguments
+    println!("{}", localize!(HELLO => {name: &name}));
 }
@@ -0,0 +1,51 @@
+use crate::utils::{
+    aliases::SynResult,
+    errors::{RequiredArgumentError, UnknownArgumentError},
+    typed_parse::TypedParse,
+};
+use proc_macro2::Ident;
+use syn::{
+    Expr, Path, Token,
+    parse::{Parse, ParseStream},
+};
+
+pub struct Arguments {
+    pub function: Ident,
+    pub writer: Expr,
+    pub locale: Option<Expr>,
+    pub expressions: Option<Vec<Path>>,
+    pub path: Option<Path>,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut function = None;
+        let mut writer = None;
+        let mut locale = None;
+        let mut expressions = None;
+        let mut path = None;
+
+        input.parse_arguments(|argument, processor| {
+            input.parse::<Token![=]>()?;
+
+            match processor.process(&argument)?.as_str() {
+                "function" => function = Some(input.parse_ident("function")?),
+                "writer" => writer = Some(input.parse()?),
+                "locale" => locale = Some(input.parse()?),
+                "expressions" => expressions = Some(input.parse_array("expressions", Path::parse)?),
+                "path" => path = Some(input.parse_path("path")?),
+                _ => Err(UnknownArgumentError::new(argument))?,
+            };
+
+            Ok(())
+        })?;
+
+        Ok(Self {
+            function: function.ok_or(RequiredArgumentError::new("function"))?,
+            writer: writer.ok_or(RequiredArgumentError::new("writer"))?,
+            locale,
+            expressions,
+            path,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    backends::export::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, names::MOD_PRIVATE},
+};
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse;
+
+pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
+    let arguments = &parse::<Arguments>(input)?;
+    let names_provider = &NamesProvider::new(arguments.path.clone());
+    let private_mod_path = names_provider.get_component_path(MOD_PRIVATE);
+    let function = &arguments.function;
+    let writer = &arguments.writer;
+    let locale = arguments.locale.as_ref().map(|locale| quote!(#locale,));
+
+    let messages = match &arguments.expressions {
+        Some(expressions) => {
+            let messages = expressions.iter().map(|expression| {
+                let mut message = expression.clone();
+                if let Some(last) = message.segments.last_mut() {
+                    last.ident = format_ident!("__{}", last.ident);
+                }
+
+                message
+            });
+
+            quote!(&[#(&#messages),*])
+        }
+        None => quote!(#private_mod_path::messages()),
+    };
+
+    Ok(quote! {
+        #private_mod_path::#function(#writer, #locale #messages)
+    }
+    .into())
+}
@@ -0,0 +1,4 @@
+mod arguments;
+mod backend;
+
+pub use backend::backend as export_backend;
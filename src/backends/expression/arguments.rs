@@ -1,29 +1,32 @@
-use crate::utils::{
-    aliases::SynResult,
-    errors::{RequiredArgumentError, UnknownArgumentError},
-    typed_parse::TypedParse,
+use crate::{
+    backends::expression::definition::Definition,
+    utils::{
+        aliases::SynResult,
+        errors::{RequiredArgumentError, UnknownArgumentError},
+        typed_parse::TypedParse,
+    },
 };
 use proc_macro2::Ident;
 use syn::{
-    Expr, Path, Token, Type,
+    LitStr, Path, Token, bracketed,
     parse::{Parse, ParseStream},
 };
 
 pub struct Arguments {
-    pub name: Ident,
-    pub r#type: Type,
-    pub locales: Vec<Ident>,
-    pub values: Vec<Expr>,
+    pub expressions: Vec<Definition>,
+    pub variants: Vec<Ident>,
+    pub tags: Vec<String>,
+    pub fallbacks: Option<Vec<Ident>>,
     pub path: Option<Path>,
     pub locale_name: String,
 }
 
 impl Parse for Arguments {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let mut name = None;
-        let mut r#type = None;
-        let mut locales = None;
-        let mut values = None;
+        let mut expressions = None;
+        let mut variants = None;
+        let mut tags = None;
+        let mut fallbacks = None;
         let mut path = None;
         let mut locale_name = None;
 
@@ -31,10 +34,28 @@ impl Parse for Arguments {
             input.parse::<Token![=]>()?;
 
             match processor.process(&argument)?.as_str() {
-                "name" => name = Some(input.parse_ident("name")?),
-                "r#type" => r#type = Some(input.parse_type("r#type")?),
-                "locales" => locales = Some(input.parse_array("locales", Ident::parse)?),
-                "values" => values = Some(input.parse_array("values", Expr::parse)?),
+                "expressions" => {
+                    let content;
+                    bracketed!(content in input);
+
+                    expressions = Some(
+                        content
+                            .parse_terminated(Definition::parse, Token![,])?
+                            .into_iter()
+                            .collect(),
+                    )
+                }
+                "variants" => variants = Some(input.parse_array("variants", Ident::parse)?),
+                "tags" => {
+                    tags = Some(
+                        input
+                            .parse_array("tags", <LitStr as Parse>::parse)?
+                            .iter()
+                            .map(LitStr::value)
+                            .collect(),
+                    )
+                }
+                "fallbacks" => fallbacks = Some(input.parse_array("fallbacks", Ident::parse)?),
                 "path" => path = Some(input.parse_path("path")?),
                 "locale_name" => locale_name = Some(input.parse_string("locale_name")?),
                 _ => Err(UnknownArgumentError::new(argument))?,
@@ -44,10 +65,10 @@ impl Parse for Arguments {
         })?;
 
         Ok(Self {
-            name: name.ok_or(RequiredArgumentError::new("name"))?,
-            r#type: r#type.ok_or(RequiredArgumentError::new("r#type"))?,
-            locales: locales.ok_or(RequiredArgumentError::new("locales"))?,
-            values: values.ok_or(RequiredArgumentError::new("values"))?,
+            expressions: expressions.ok_or(RequiredArgumentError::new("expressions"))?,
+            variants: variants.ok_or(RequiredArgumentError::new("variants"))?,
+            tags: tags.ok_or(RequiredArgumentError::new("tags"))?,
+            fallbacks,
             path,
             locale_name: locale_name.ok_or(RequiredArgumentError::new("locale_name"))?,
         })
@@ -1,15 +1,30 @@
 use crate::{
     backends::expression::{arguments::Arguments, static_expression::static_expression},
-    utils::{NamesProvider, aliases::SynResult},
+    utils::{NamesProvider, aliases::SynResult, combine_errors},
 };
 use proc_macro::TokenStream;
+use quote::quote;
 use syn::parse;
 
 pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
     let arguments = &parse::<Arguments>(input)?;
     let names_provider = &NamesProvider::new(arguments.path.clone());
 
-    let expression = static_expression(arguments, names_provider);
+    let mut expressions = Vec::new();
+    let mut errors = Vec::new();
+    for definition in &arguments.expressions {
+        match static_expression(arguments, definition, names_provider) {
+            Ok(expression) => expressions.push(expression),
+            Err(error) => errors.push(error),
+        }
+    }
 
-    Ok(expression.into())
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    Ok(quote! {
+        #(#expressions)*
+    }
+    .into())
 }
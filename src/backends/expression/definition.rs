@@ -0,0 +1,192 @@
+use crate::utils::{
+    aliases::SynResult,
+    errors::{UnknownPluralCategoryError, UnknownSegmentStateError},
+    plural_rules::CATEGORIES,
+    template::Template,
+};
+use proc_macro2::{Ident, Span};
+use syn::{
+    Attribute, Expr, LitStr, Token, Type, braced, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Paren,
+};
+
+pub const STATES: [&str; 4] = ["initial", "translated", "reviewed", "final"];
+
+mod kw {
+    syn::custom_keyword!(fmt);
+    syn::custom_keyword!(plural);
+}
+
+pub struct Definition {
+    pub attributes: Vec<Attribute>,
+    pub states: Vec<(Ident, LitStr)>,
+    pub name: Ident,
+    pub kind: Kind,
+    pub arms: Vec<Arm>,
+}
+
+pub enum Kind {
+    Value(Type),
+    Plural(Type),
+    Format(Vec<(Ident, Type)>),
+}
+
+pub struct Arm {
+    pub pattern: Pattern,
+    pub value: Value,
+}
+
+pub enum Pattern {
+    Variants(Vec<Ident>),
+    Wildcard(Token![_]),
+}
+
+pub enum Value {
+    Expr(Box<Expr>),
+    Plural(Vec<(Ident, Template)>),
+    Template(Template),
+}
+
+impl Parse for Definition {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut attributes = Vec::new();
+        let mut states = Vec::new();
+        for attribute in input.call(Attribute::parse_outer)? {
+            if attribute.path().is_ident("state") {
+                states.extend(attribute.parse_args_with(|input: ParseStream| {
+                    input.parse_terminated(parse_state, Token![,])
+                })?);
+            } else {
+                attributes.push(attribute);
+            }
+        }
+        let name = input.parse()?;
+        let kind = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+
+            if input.peek(kw::plural) && (input.peek2(Token![=>]) || input.peek2(Paren)) {
+                input.parse::<kw::plural>()?;
+
+                Kind::Plural(if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+
+                    content.parse()?
+                } else {
+                    parse_quote!(u64)
+                })
+            } else if input.peek(kw::fmt) && input.peek2(Paren) {
+                input.parse::<kw::fmt>()?;
+
+                let content;
+                parenthesized!(content in input);
+
+                Kind::Format(
+                    content
+                        .parse_terminated(parse_parameter, Token![,])?
+                        .into_iter()
+                        .collect(),
+                )
+            } else {
+                Kind::Value(input.parse()?)
+            }
+        } else {
+            Kind::Value(parse_quote!(&'static str))
+        };
+        input.parse::<Token![=>]>()?;
+
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(Arm::parse(&content, &kind)?);
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            attributes,
+            states,
+            name,
+            kind,
+            arms,
+        })
+    }
+}
+
+impl Arm {
+    fn parse(input: ParseStream, kind: &Kind) -> SynResult<Self> {
+        let pattern = if input.peek(Token![_]) {
+            Pattern::Wildcard(input.parse()?)
+        } else {
+            Pattern::Variants(
+                Punctuated::<Ident, Token![|]>::parse_separated_nonempty(input)?
+                    .into_iter()
+                    .collect(),
+            )
+        };
+        input.parse::<Token![:]>()?;
+
+        let value = match kind {
+            Kind::Value(_) => Value::Expr(Box::new(input.parse()?)),
+            Kind::Plural(_) => {
+                let content;
+                braced!(content in input);
+
+                Value::Plural(
+                    content
+                        .parse_terminated(parse_plural_category, Token![,])?
+                        .into_iter()
+                        .collect(),
+                )
+            }
+            Kind::Format(_) => Value::Template(input.parse()?),
+        };
+
+        Ok(Self { pattern, value })
+    }
+}
+
+impl Pattern {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Variants(variants) => variants[0].span(),
+            Self::Wildcard(underscore) => underscore.span(),
+        }
+    }
+}
+
+fn parse_plural_category(input: ParseStream) -> SynResult<(Ident, Template)> {
+    let category = input.parse::<Ident>()?;
+    if !CATEGORIES.contains(&category.to_string().as_str()) {
+        Err(UnknownPluralCategoryError::new(&category))?;
+    }
+    input.parse::<Token![:]>()?;
+
+    Ok((category, input.parse()?))
+}
+
+fn parse_state(input: ParseStream) -> SynResult<(Ident, LitStr)> {
+    let variant = input.parse()?;
+    input.parse::<Token![=]>()?;
+
+    let state = input.parse::<LitStr>()?;
+    if !STATES.contains(&state.value().as_str()) {
+        Err(UnknownSegmentStateError::new(&state))?;
+    }
+
+    Ok((variant, state))
+}
+
+fn parse_parameter(input: ParseStream) -> SynResult<(Ident, Type)> {
+    let name = input.parse()?;
+    input.parse::<Token![:]>()?;
+
+    Ok((name, input.parse()?))
+}
@@ -0,0 +1,149 @@
+use crate::{
+    backends::expression::{
+        arguments::Arguments,
+        definition::{Arm, Definition, Kind, Value},
+    },
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        combine_errors,
+        errors::{DuplicateLocaleVariantError, UnknownLocaleVariantError},
+        names::{MACRO_REGISTER, MOD_PRIVATE},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, MetaNameValue, Type, ext::IdentExt};
+
+pub fn export_message(
+    arguments: &Arguments,
+    definition: &Definition,
+    arms: &[&Arm],
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
+    let states = states(arguments, definition)?;
+    if !cfg!(any(
+        feature = "from_po",
+        feature = "from_json",
+        feature = "from_csv",
+        feature = "from_xliff"
+    )) {
+        return Ok(TokenStream::new());
+    }
+
+    let private_mod_path = names_provider.get_component_path(MOD_PRIVATE);
+    let register_ident = NamesProvider::get_name(MACRO_REGISTER);
+    let name = format_ident!("__{}", definition.name);
+    let path = format!("::{}", definition.name.unraw());
+    let message = match &definition.kind {
+        Kind::Value(r#type) if is_str(r#type) => {
+            let texts = arms
+                .iter()
+                .map(|arm| match &arm.value {
+                    Value::Expr(expr) => match expr.as_ref() {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(literal),
+                            ..
+                        }) => Some(literal.value().replace('{', "{{").replace('}', "}}")),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+
+            match texts {
+                Some(texts) => quote!(Text([#(#texts),*])),
+                None => return Ok(TokenStream::new()),
+            }
+        }
+        Kind::Value(_) => return Ok(TokenStream::new()),
+        Kind::Format(_) => {
+            let templates = arms.iter().filter_map(|arm| match &arm.value {
+                Value::Template(template) => Some(template.literal.value()),
+                _ => None,
+            });
+
+            quote!(Text([#(#templates),*]))
+        }
+        Kind::Plural(_) => {
+            let categories = arms.iter().filter_map(|arm| match &arm.value {
+                Value::Plural(categories) => {
+                    let (categories, templates) = categories
+                        .iter()
+                        .map(|(category, template)| {
+                            (category.to_string(), template.literal.value())
+                        })
+                        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+                    Some(quote!(&[#((#categories, #templates)),*]))
+                }
+                _ => None,
+            });
+
+            quote!(Plural([#(#categories),*]))
+        }
+    };
+
+    let note = note(&definition.attributes);
+
+    Ok(quote! {
+        #[doc(hidden)]
+        pub const #name: #private_mod_path::Message = #private_mod_path::Message {
+            path: core::concat!(core::module_path!(), #path),
+            note: #note,
+            states: [#(#states),*],
+            value: #private_mod_path::MessageValue::#message,
+        };
+
+        #private_mod_path::#register_ident!(#name);
+    })
+}
+
+fn states(arguments: &Arguments, definition: &Definition) -> SynResult<Vec<String>> {
+    let variants = &arguments.variants;
+
+    let mut errors = Vec::new();
+    let mut states = vec![None; variants.len()];
+    for (variant, state) in &definition.states {
+        match variants.iter().position(|candidate| candidate == variant) {
+            Some(i) if states[i].is_some() => {
+                errors.push(DuplicateLocaleVariantError::new(variant))
+            }
+            Some(i) => states[i] = Some(state.value()),
+            None => errors.push(UnknownLocaleVariantError::new(variant, variants)),
+        }
+    }
+
+    combine_errors(errors).map_or(
+        Ok(states.into_iter().map(Option::unwrap_or_default).collect()),
+        Err,
+    )
+}
+
+fn note(attributes: &[Attribute]) -> String {
+    attributes
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(MetaNameValue {
+                path,
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(literal),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => Some(literal.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_str(r#type: &Type) -> bool {
+    matches!(
+        r#type,
+        Type::Reference(reference)
+            if matches!(reference.elem.as_ref(), Type::Path(path) if path.path.is_ident("str"))
+    )
+}
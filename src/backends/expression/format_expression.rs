@@ -0,0 +1,71 @@
+use crate::{
+    backends::expression::{
+        arguments::Arguments,
+        definition::{Arm, Definition, Value},
+    },
+    utils::NamesProvider,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Type, ext::IdentExt};
+
+pub fn format_expression(
+    arguments: &Arguments,
+    definition: &Definition,
+    parameters: &[(Ident, Type)],
+    arms: &[&Arm],
+    names_provider: &NamesProvider,
+) -> TokenStream {
+    let locale_path = names_provider.get_component_path(&arguments.locale_name);
+    let attributes = &definition.attributes;
+    let name = &definition.name;
+    let (names, types) = parameters.iter().cloned().unzip::<_, _, Vec<_>, Vec<_>>();
+    let generics = (0..parameters.len())
+        .map(|i| format_ident!("T{i}"))
+        .collect::<Vec<_>>();
+
+    let values = arms.iter().filter_map(|arm| match &arm.value {
+        Value::Template(template) => {
+            let arguments = if template.placeholders().next().is_some() {
+                quote!(arguments)
+            } else {
+                quote!(_)
+            };
+            let formatter = if template.pieces.is_empty() {
+                quote!(_)
+            } else {
+                quote!(formatter)
+            };
+            let body = template.write(|placeholder| {
+                let placeholder = names
+                    .iter()
+                    .find(|name| name.unraw() == placeholder)
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("{placeholder}"));
+
+                quote!(&arguments.#placeholder)
+            });
+
+            Some(quote! {
+                |#arguments, #formatter| {
+                    #body
+                }
+            })
+        }
+        _ => None,
+    });
+
+    quote! {
+        #(#attributes)*
+        #[allow(non_camel_case_types)]
+        pub struct #name<#(#generics),*> {
+            #(pub #names: #generics),*
+        }
+
+        #(#attributes)*
+        pub static #name: [
+            fn(&#name<#(#types),*>, &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+            #locale_path::COUNT
+        ] = [#(#values),*];
+    }
+}
@@ -1,5 +1,13 @@
 mod arguments;
 mod backend;
+mod definition;
+mod export_message;
+mod format_expression;
+mod placeholders;
+mod plural_expression;
 mod static_expression;
 
 pub use crate::backends::expression::backend::backend as expression_backend;
+
+#[cfg(feature = "from_xliff")]
+pub use crate::backends::expression::definition::STATES;
@@ -0,0 +1,176 @@
+use crate::{
+    backends::expression::definition::{Definition, Kind, Value},
+    utils::{
+        aliases::SynError,
+        errors::{MissingPlaceholderError, UnknownPlaceholderError},
+        template::Template,
+    },
+};
+use proc_macro2::{Ident, Span};
+use syn::ext::IdentExt;
+
+pub fn check_placeholders(definition: &Definition) -> Vec<SynError> {
+    let placeholders = match &definition.kind {
+        Kind::Value(_) => return Vec::new(),
+        Kind::Plural(_) => vec![Ident::new("n", Span::call_site())],
+        Kind::Format(parameters) => parameters.iter().map(|(name, _)| name.unraw()).collect(),
+    };
+
+    let values = definition
+        .arms
+        .iter()
+        .filter_map(|arm| match &arm.value {
+            Value::Plural(categories) => {
+                let other = categories
+                    .iter()
+                    .find(|(category, _)| category == "other")
+                    .or(categories.first())?;
+
+                Some((
+                    &other.1,
+                    categories
+                        .iter()
+                        .map(|(_, template)| template)
+                        .collect::<Vec<_>>(),
+                ))
+            }
+            Value::Template(template) => Some((template, vec![template])),
+            Value::Expr(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = Vec::new();
+    let mut used = Vec::new();
+    for template in values.iter().flat_map(|(_, templates)| templates) {
+        for placeholder in template.placeholders() {
+            if !placeholders.iter().any(|name| name == placeholder) {
+                errors.push(UnknownPlaceholderError::new(
+                    &template.literal,
+                    placeholder,
+                    &placeholders,
+                ));
+            } else if !used.contains(&placeholder) {
+                used.push(placeholder);
+            }
+        }
+    }
+
+    for (literal_template, templates) in &values {
+        let missing = used
+            .iter()
+            .filter(|placeholder| !uses(templates, placeholder))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            errors.push(MissingPlaceholderError::new(
+                &literal_template.literal,
+                &missing,
+            ));
+        }
+    }
+
+    errors
+}
+
+fn uses(templates: &[&Template], placeholder: &str) -> bool {
+    templates
+        .iter()
+        .any(|template| template.placeholders().any(|used| used == placeholder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_placeholders;
+    use crate::backends::expression::definition::Definition;
+
+    fn errors(input: &str) -> Vec<(String, usize)> {
+        let definition = syn::parse_str::<Definition>(input).unwrap();
+
+        check_placeholders(&definition)
+            .into_iter()
+            .map(|error| (error.to_string(), error.span().start().column))
+            .collect()
+    }
+
+    fn column(input: &str, literal: &str) -> usize {
+        input.find(literal).unwrap()
+    }
+
+    #[test]
+    fn consistent_placeholders() {
+        assert_eq!(
+            errors(r#"HELLO: fmt(name: &str) => { En: "Hi, {name}!", Ru: "Privet, {name}!" }"#),
+            []
+        );
+        assert_eq!(
+            errors(r#"EMPTY: fmt() => { En: "Hi!", Ru: "Privet!" }"#),
+            []
+        );
+    }
+
+    #[test]
+    fn dropped_placeholder() {
+        let input = r#"HELLO: fmt(name: &str) => { En: "Hi, {name}!", Ru: "Privet!" }"#;
+
+        assert_eq!(
+            errors(input),
+            [(
+                "Missing placeholders used by other locales: `{name}`".to_string(),
+                column(input, r#""Privet!""#)
+            )]
+        );
+    }
+
+    #[test]
+    fn extra_placeholder() {
+        let input =
+            r#"HELLO: fmt(name: &str) => { En: "Hi, {name}!", Ru: "Privet, {name} {age}!" }"#;
+
+        assert_eq!(
+            errors(input),
+            [(
+                "Unknown placeholder `{age}`".to_string(),
+                column(input, r#""Privet, "#)
+            )]
+        );
+    }
+
+    #[test]
+    fn transposed_placeholder() {
+        let input = r#"HELLO: fmt(name: &str) => { En: "Hi, {name}!", Ru: "Privet, {naem}!" }"#;
+
+        assert_eq!(
+            errors(input),
+            [
+                (
+                    "Unknown placeholder `{naem}`, did you mean `{name}`?".to_string(),
+                    column(input, r#""Privet, "#)
+                ),
+                (
+                    "Missing placeholders used by other locales: `{name}`".to_string(),
+                    column(input, r#""Privet, "#)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn plural_placeholder() {
+        let input = r#"ITEMS: plural => { En: { one: "{n} item", other: "{n} items" }, Ru: { other: "{count}" } }"#;
+
+        assert_eq!(
+            errors(input),
+            [
+                (
+                    "Unknown placeholder `{count}`".to_string(),
+                    column(input, r#""{count}""#)
+                ),
+                (
+                    "Missing placeholders used by other locales: `{n}`".to_string(),
+                    column(input, r#""{count}""#)
+                ),
+            ]
+        );
+    }
+}
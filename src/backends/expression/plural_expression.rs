@@ -0,0 +1,154 @@
+use crate::{
+    backends::expression::{
+        arguments::Arguments,
+        definition::{Arm, Definition, Value},
+    },
+    utils::{
+        NamesProvider,
+        aliases::{SynError, SynResult},
+        combine_errors,
+        errors::{
+            DuplicatePluralCategoryError, MissingPluralCategoryError, NoPluralRulesError,
+            UnexpectedPluralCategoryError,
+        },
+        language_tag::LanguageTag,
+        names::{MOD_PLURAL, MOD_PRIVATE},
+        plural_rules::{PluralRules, category_ident},
+        template::Template,
+    },
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::ptr;
+use syn::Type;
+
+pub fn plural_expression(
+    arguments: &Arguments,
+    definition: &Definition,
+    count_type: &Type,
+    arms: &[&Arm],
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
+    let locale_path = names_provider.get_component_path(&arguments.locale_name);
+    let plural_mod_path = names_provider.get_component_path(MOD_PLURAL);
+    let private_mod_path = names_provider.get_component_path(MOD_PRIVATE);
+    let attributes = &definition.attributes;
+    let name = &definition.name;
+
+    let mut errors = Vec::new();
+    let mut checked = Vec::new();
+    let mut values = Vec::new();
+    for ((variant, tag), arm) in arguments.variants.iter().zip(&arguments.tags).zip(arms) {
+        let Value::Plural(categories) = &arm.value else {
+            continue;
+        };
+
+        let [language, ..] = LanguageTag::matching_subtags(tag);
+        let Some(rules) = PluralRules::new(&language) else {
+            errors.push(NoPluralRulesError::new(name, variant, &language));
+            continue;
+        };
+
+        let required = rules.categories();
+        if !checked.iter().any(|(checked_arm, checked_required)| {
+            ptr::eq(*checked_arm, *arm) && *checked_required == required
+        }) {
+            errors.extend(check_categories(arm, categories, &required, variant));
+            checked.push((*arm, required));
+        }
+
+        let mut other = None;
+        let mut formats = Vec::new();
+        for (category, template) in categories {
+            let format = format(template, count_type);
+
+            if category == "other" {
+                other = Some(format);
+            } else {
+                let category = category_ident(&category.to_string());
+
+                formats.push(quote! {
+                    #plural_mod_path::PluralCategory::#category => #format
+                });
+            }
+        }
+
+        values.push(quote! {
+            |n: #count_type| #private_mod_path::Formatted::<#count_type>::new(
+                match #locale_path::#variant.plural_category(n) {
+                    #(#formats,)*
+                    _ => #other,
+                },
+                n,
+            )
+        });
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    Ok(quote! {
+        #(#attributes)*
+        pub static #name: [
+            fn(#count_type) -> #private_mod_path::Formatted<#count_type>;
+            #locale_path::COUNT
+        ] = [#(#values),*];
+    })
+}
+
+fn check_categories(
+    arm: &Arm,
+    categories: &[(Ident, Template)],
+    required: &[&str],
+    variant: &Ident,
+) -> Vec<SynError> {
+    let mut errors = Vec::new();
+    let mut provided = Vec::new();
+    for (category, _) in categories {
+        let category_name = category.to_string();
+
+        if provided.contains(&category_name) {
+            errors.push(DuplicatePluralCategoryError::new(category));
+        } else if !required.contains(&category_name.as_str()) {
+            errors.push(UnexpectedPluralCategoryError::new(category, variant));
+        }
+
+        provided.push(category_name);
+    }
+
+    let missing = required
+        .iter()
+        .filter(|category| !provided.iter().any(|provided| provided == *category))
+        .copied()
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        errors.push(MissingPluralCategoryError::new(
+            arm.pattern.span(),
+            variant,
+            &missing,
+        ));
+    }
+
+    errors
+}
+
+fn format(template: &Template, count_type: &Type) -> TokenStream {
+    let argument = if template.placeholders().next().is_some() {
+        quote!(n)
+    } else {
+        quote!(_)
+    };
+    let formatter = if template.pieces.is_empty() {
+        quote!(_)
+    } else {
+        quote!(formatter)
+    };
+    let body = template.write(|_| quote!(n));
+
+    quote! {
+        |#argument: &#count_type, #formatter: &mut core::fmt::Formatter<'_>| -> core::fmt::Result {
+            #body
+        }
+    }
+}
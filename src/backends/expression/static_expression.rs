@@ -1,34 +1,214 @@
-use crate::{backends::expression::arguments::Arguments, utils::NamesProvider};
-use proc_macro2::TokenStream;
+use crate::{
+    backends::expression::{
+        arguments::Arguments,
+        definition::{Arm, Definition, Kind, Pattern, Value},
+        export_message::export_message,
+        format_expression::format_expression,
+        placeholders::check_placeholders,
+        plural_expression::plural_expression,
+    },
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        combine_errors,
+        errors::{
+            DuplicateLocaleVariantError, DuplicateWildcardError, MissingLocaleVariantError,
+            UnknownLocaleVariantError,
+        },
+    },
+};
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-pub fn static_expression(arguments: &Arguments, names_provider: &NamesProvider) -> TokenStream {
+pub fn static_expression(
+    arguments: &Arguments,
+    definition: &Definition,
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
     let locale_path = names_provider.get_component_path(&arguments.locale_name);
-    let name = &arguments.name;
-    let r#type = &arguments.r#type;
-    let locales = &arguments.locales;
-    let values = &arguments.values;
-
-    quote! {
-        pub static #name: [#r#type; #locale_path::COUNT] = {
-            let mut expression = [#(#values),*];
-            let mut empty = [true; #locale_path::COUNT];
-
-            #(
-                let i = #locale_path::#locales.to_usize();
-
-                if core::mem::replace(&mut empty[i], false) {
-                    expression[i] = #values;
-                } else {
-                    panic!(concat!(
-                        "Locale variant ",
-                        stringify!(locales),
-                        " is duplicated"
-                    ));
+    let attributes = &definition.attributes;
+    let name = &definition.name;
+    let arms = variants_arms(arguments, definition)?;
+
+    let expression = match &definition.kind {
+        Kind::Value(r#type) => {
+            let values = arms.iter().filter_map(|arm| match &arm.value {
+                Value::Expr(expr) => Some(expr),
+                _ => None,
+            });
+
+            quote! {
+                #(#attributes)*
+                pub static #name: [#r#type; #locale_path::COUNT] = [#(#values),*];
+            }
+        }
+        Kind::Plural(count_type) => {
+            plural_expression(arguments, definition, count_type, &arms, names_provider)?
+        }
+        Kind::Format(parameters) => {
+            format_expression(arguments, definition, parameters, &arms, names_provider)
+        }
+    };
+    let message = export_message(arguments, definition, &arms, names_provider)?;
+
+    Ok(quote! {
+        #expression
+        #message
+    })
+}
+
+fn variants_arms<'a>(arguments: &Arguments, definition: &'a Definition) -> SynResult<Vec<&'a Arm>> {
+    let variants = &arguments.variants;
+    let find_variant = |locale: &Ident| variants.iter().position(|variant| variant == locale);
+
+    let mut errors = check_placeholders(definition);
+    let mut arms = vec![None; variants.len()];
+    let mut wildcard = None;
+    for arm in &definition.arms {
+        match &arm.pattern {
+            Pattern::Variants(locales) => {
+                for locale in locales {
+                    match find_variant(locale) {
+                        Some(i) if arms[i].is_some() => {
+                            errors.push(DuplicateLocaleVariantError::new(locale))
+                        }
+                        Some(i) => arms[i] = Some(arm),
+                        None => errors.push(UnknownLocaleVariantError::new(locale, variants)),
+                    }
+                }
+            }
+            Pattern::Wildcard(underscore) => {
+                if wildcard.replace(arm).is_some() {
+                    errors.push(DuplicateWildcardError::new(underscore));
+                }
+            }
+        }
+    }
+
+    if let Some(wildcard) = wildcard {
+        arms.iter_mut()
+            .filter(|arm| arm.is_none())
+            .for_each(|arm| *arm = Some(wildcard));
+    }
+
+    let fallbacks = arguments.fallbacks.as_ref().map(|fallbacks| {
+        fallbacks
+            .iter()
+            .map(|fallback| find_variant(fallback).unwrap_or_default())
+            .collect::<Vec<_>>()
+    });
+
+    let mut variants_arms = Vec::new();
+    let mut missing = Vec::new();
+    for i in 0..variants.len() {
+        let mut current = i;
+
+        loop {
+            if let Some(arm) = arms[current] {
+                variants_arms.push(arm);
+                break;
+            }
+
+            match &fallbacks {
+                Some(fallbacks) if fallbacks[current] != current => current = fallbacks[current],
+                _ => {
+                    if !missing.contains(&&variants[current]) {
+                        missing.push(&variants[current]);
+                    }
+                    break;
                 }
-            )*
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        errors.push(MissingLocaleVariantError::new(&definition.name, &missing));
+    }
+
+    combine_errors(errors).map_or(Ok(variants_arms), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::static_expression;
+    use crate::{backends::expression::arguments::Arguments, utils::NamesProvider};
+    use proc_macro2::LineColumn;
+
+    fn errors(expressions: &str, fallbacks: &str) -> Vec<(String, LineColumn)> {
+        let arguments = syn::parse_str::<Arguments>(&format!(
+            "expressions = [{expressions}], variants = [En, EnGb, Ru, Uk], \
+            tags = [\"en\", \"en-GB\", \"ru\", \"uk\"], {fallbacks} locale_name = \"Locale\""
+        ))
+        .unwrap();
+        let names_provider = NamesProvider::new(None);
+
+        arguments
+            .expressions
+            .iter()
+            .filter_map(|definition| {
+                static_expression(&arguments, definition, &names_provider).err()
+            })
+            .flat_map(|error| error.into_iter())
+            .map(|error| (error.to_string(), error.span().start()))
+            .collect()
+    }
+
+    fn at(column: usize) -> LineColumn {
+        LineColumn { line: 1, column }
+    }
+
+    #[test]
+    fn unknown_variant() {
+        assert_eq!(
+            errors(
+                r#"HELLO => {En: "", EnGb: "", Ru: "", Uk: "", Rus: ""}"#,
+                ""
+            ),
+            [(
+                "Unknown locale variant `Rus`, did you mean `Ru`?".to_string(),
+                at(59)
+            )]
+        );
+        assert_eq!(
+            errors(r#"HELLO => {En: "", EnGb: "", Ru: "", Uk: "", Fr: ""}"#, ""),
+            [("Unknown locale variant `Fr`".to_string(), at(59))]
+        );
+    }
+
+    #[test]
+    fn duplicate_variant() {
+        assert_eq!(
+            errors(r#"HELLO => {En | EnGb: "", Ru: "", Uk | En: ""}"#, ""),
+            [("Locale variant `En` is duplicated".to_string(), at(53))]
+        );
+    }
 
-            expression
-        };
+    #[test]
+    fn missing_variants() {
+        assert_eq!(
+            errors(r#"HELLO => {En: "", Uk: ""}"#, ""),
+            [(
+                "Missing locale variants in `HELLO`: `EnGb`, `Ru`".to_string(),
+                at(15)
+            )]
+        );
+        assert_eq!(
+            errors(
+                r#"HELLO => {Ru: "", Uk: ""}"#,
+                "fallbacks = [En, En, En, Ru],"
+            ),
+            [(
+                "Missing locale variants in `HELLO`: `En`".to_string(),
+                at(15)
+            )]
+        );
+        assert_eq!(
+            errors(
+                r#"HELLO => {En: "", Ru: ""}"#,
+                "fallbacks = [En, En, En, Ru],"
+            ),
+            []
+        );
+        assert_eq!(errors(r#"HELLO => {En: "", _: ""}"#, ""), []);
     }
 }
@@ -0,0 +1,307 @@
+use crate::{
+    backends::expressions_from_csv::parser::{Record, parse_records},
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        catalog::{Catalog, CatalogArguments, Resource, Translation},
+        closest, combine_errors,
+        plural_rules::CATEGORIES,
+        template::{Piece, parse_pieces},
+    },
+};
+use proc_macro::TokenStream;
+use syn::parse;
+
+struct Columns {
+    key: usize,
+    notes: Option<usize>,
+    variants: Vec<usize>,
+}
+
+enum Kind {
+    Text(Vec<Option<Vec<Piece>>>),
+    Plural(Vec<Vec<(String, Vec<Piece>)>>),
+}
+
+struct Entry {
+    key: Vec<String>,
+    names: Vec<String>,
+    line: usize,
+    note: String,
+    kind: Kind,
+}
+
+pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
+    let arguments = &parse::<CatalogArguments>(input)?;
+    let names_provider = &NamesProvider::new(arguments.path.clone());
+
+    let resource = &Resource::read(&arguments.file, arguments.file.value())?;
+    let mut catalog = Catalog::new(arguments, vec![resource; arguments.variants.len()]);
+
+    let records = parse_records(&arguments.file, resource)?;
+    let Some((header, rows)) = records.split_first() else {
+        Err(resource.error(&arguments.file, 1, "missing the header row"))?
+    };
+    let columns = columns(arguments, resource, header)?;
+
+    let mut entries = Vec::<Entry>::new();
+    let mut errors = Vec::new();
+    for row in rows {
+        if let Err(error) = add_row(arguments, resource, &columns, row, &mut entries) {
+            errors.push(error);
+        }
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    for entry in entries {
+        insert(arguments, resource, &mut catalog, entry)?;
+    }
+
+    Ok(catalog.expand(names_provider)?.into())
+}
+
+fn columns(
+    arguments: &CatalogArguments,
+    resource: &Resource,
+    header: &Record,
+) -> SynResult<Columns> {
+    let file = &arguments.file;
+    let error = |message: String| resource.error(file, header.line, message);
+    let candidates = arguments
+        .variants
+        .iter()
+        .map(ToString::to_string)
+        .chain(arguments.tags.iter().cloned())
+        .collect::<Vec<_>>();
+
+    let mut key = None;
+    let mut notes = None;
+    let mut variants = vec![None::<usize>; arguments.variants.len()];
+    let mut errors = Vec::new();
+    for (i, column) in header.fields.iter().enumerate() {
+        let column = column.trim();
+
+        if column.eq_ignore_ascii_case("key") {
+            key = Some(i);
+        } else if column.eq_ignore_ascii_case("notes") {
+            notes = Some(i);
+        } else if let Some(variant) =
+            arguments
+                .variants
+                .iter()
+                .zip(&arguments.tags)
+                .position(|(variant, tag)| {
+                    variant.to_string().eq_ignore_ascii_case(column)
+                        || tag.eq_ignore_ascii_case(column)
+                })
+        {
+            match variants[variant] {
+                Some(other) => errors.push(error(format!(
+                    "columns `{}` and `{column}` both match the `{}` locale variant",
+                    header.fields[other].trim(),
+                    arguments.variants[variant]
+                ))),
+                None => variants[variant] = Some(i),
+            }
+        } else {
+            let suggestion = closest(&column, &candidates).map_or(String::new(), |closest| {
+                format!(", did you mean `{closest}`?")
+            });
+
+            errors.push(error(format!(
+                "column `{column}` matches no locale variant{suggestion}"
+            )));
+        }
+    }
+
+    for (variant, column) in variants.iter().enumerate() {
+        if column.is_none() {
+            errors.push(error(format!(
+                "no column for the `{}` locale variant (`{}`)",
+                arguments.variants[variant], arguments.tags[variant]
+            )));
+        }
+    }
+    if key.is_none() {
+        errors.push(error("missing the `key` column".to_string()));
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    Ok(Columns {
+        key: key.unwrap_or_default(),
+        notes,
+        variants: variants.into_iter().flatten().collect(),
+    })
+}
+
+fn add_row(
+    arguments: &CatalogArguments,
+    resource: &Resource,
+    columns: &Columns,
+    row: &Record,
+    entries: &mut Vec<Entry>,
+) -> SynResult<()> {
+    let error = |message: String| resource.error(&arguments.file, row.line, message);
+    let field = |column: usize| row.fields.get(column).map_or("", String::as_str);
+
+    let name = field(columns.key).trim();
+    if name.is_empty() {
+        Err(error("a row without a key".to_string()))?;
+    }
+
+    let (path, category) = match name.strip_suffix(']').and_then(|name| name.split_once('[')) {
+        Some((path, category)) => {
+            if !CATEGORIES.contains(&category) {
+                Err(error(format!(
+                    "unknown plural category `{category}` of `{path}`"
+                )))?;
+            }
+
+            (path, Some(category))
+        }
+        None => (name, None),
+    };
+    let key = path.split('.').map(str::to_string).collect::<Vec<_>>();
+    if key.iter().any(String::is_empty) {
+        Err(error(format!("invalid key `{name}`")))?;
+    }
+
+    let translations = columns
+        .variants
+        .iter()
+        .map(|column| {
+            let template = field(*column);
+
+            if template.is_empty() {
+                Ok(None)
+            } else {
+                parse_pieces(template)
+                    .map(Some)
+                    .ok_or_else(|| error(format!("invalid template of `{name}`")))
+            }
+        })
+        .collect::<SynResult<Vec<_>>>()?;
+    let note = columns.notes.map_or("", field);
+
+    let index = match entries.iter().position(|entry| entry.key == key) {
+        Some(index) => index,
+        None => {
+            entries.push(Entry {
+                key,
+                names: Vec::new(),
+                line: row.line,
+                note: String::new(),
+                kind: match category {
+                    Some(_) => Kind::Plural(vec![Vec::new(); translations.len()]),
+                    None => Kind::Text(Vec::new()),
+                },
+            });
+
+            entries.len() - 1
+        }
+    };
+    let entry = &mut entries[index];
+    if entry.names.iter().any(|other| other == name) {
+        Err(error(format!("duplicate key `{name}`")))?;
+    }
+    entry.names.push(name.to_string());
+    if entry.note.is_empty() {
+        entry.note = note.to_string();
+    }
+
+    match (&mut entry.kind, category) {
+        (Kind::Text(texts), None) => *texts = translations,
+        (Kind::Plural(variants), Some(category)) => {
+            for (categories, pieces) in variants.iter_mut().zip(translations) {
+                if let Some(pieces) = pieces {
+                    categories.push((category.to_string(), pieces));
+                }
+            }
+        }
+        _ => Err(error(format!(
+            "`{path}` is used both as a plain and as a plural message"
+        )))?,
+    }
+
+    Ok(())
+}
+
+fn insert(
+    arguments: &CatalogArguments,
+    resource: &Resource,
+    catalog: &mut Catalog,
+    entry: Entry,
+) -> SynResult<()> {
+    let translated = match &entry.kind {
+        Kind::Text(texts) => texts.iter().any(Option::is_some),
+        Kind::Plural(variants) => variants.iter().any(|categories| !categories.is_empty()),
+    };
+    if !translated {
+        Err(resource.error(
+            &arguments.file,
+            entry.line,
+            format!("`{}` has no translations", entry.key.join(".")),
+        ))?;
+    }
+    if !entry.note.is_empty() {
+        catalog.note(entry.key.clone(), entry.note);
+    }
+
+    match entry.kind {
+        Kind::Text(texts) => {
+            for (variant, pieces) in texts.into_iter().enumerate() {
+                if let Some(pieces) = pieces {
+                    catalog.insert(variant, entry.key.clone(), Translation::Text(pieces));
+                }
+            }
+        }
+        Kind::Plural(variants) => {
+            for (variant, categories) in variants.into_iter().enumerate() {
+                if categories.is_empty() {
+                    continue;
+                }
+
+                let default = categories
+                    .iter()
+                    .position(|(category, _)| category == "other")
+                    .ok_or_else(|| {
+                        resource.error(
+                            &arguments.file,
+                            entry.line,
+                            format!(
+                                "`{}` has no `other` form for `{}`",
+                                entry.key.join("."),
+                                arguments.variants[variant]
+                            ),
+                        )
+                    })?;
+                let selector = categories[default]
+                    .1
+                    .iter()
+                    .find_map(|piece| match piece {
+                        Piece::Placeholder(placeholder) => Some(placeholder.clone()),
+                        Piece::Text(_) => None,
+                    })
+                    .unwrap_or("n".to_string());
+
+                catalog.insert(
+                    variant,
+                    entry.key.clone(),
+                    Translation::Plural {
+                        selector,
+                        categories,
+                        default,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
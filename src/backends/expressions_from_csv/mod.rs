@@ -0,0 +1,4 @@
+mod backend;
+mod parser;
+
+pub use backend::backend as expressions_from_csv_backend;
@@ -0,0 +1,68 @@
+use crate::utils::{aliases::SynResult, catalog::Resource};
+use syn::LitStr;
+
+pub struct Record {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+pub fn parse_records(file: &LitStr, resource: &Resource) -> SynResult<Vec<Record>> {
+    let content = resource
+        .content
+        .strip_prefix('\u{feff}')
+        .unwrap_or(&resource.content);
+    let mut chars = content.chars().peekable();
+
+    let mut records = Vec::new();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+
+        loop {
+            match chars.next() {
+                Some('"') if field.is_empty() => loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => match chars.peek() {
+                            Some(',' | '\n') | None => break,
+                            Some(_) => Err(resource.error(
+                                file,
+                                line,
+                                "expected `,` or a line break after a quoted field",
+                            ))?,
+                        },
+                        Some(char) => {
+                            if char == '\n' {
+                                line += 1;
+                            }
+                            field.push(char);
+                        }
+                        None => Err(resource.error(file, start, "unterminated quoted field"))?,
+                    }
+                },
+                Some(',') => fields.push(std::mem::take(&mut field)),
+                Some('\n') => {
+                    line += 1;
+                    break;
+                }
+                Some(char) => field.push(char),
+                None => break,
+            }
+        }
+        fields.push(field);
+
+        if fields.iter().any(|field| !field.is_empty()) {
+            records.push(Record {
+                line: start,
+                fields,
+            });
+        }
+    }
+
+    Ok(records)
+}
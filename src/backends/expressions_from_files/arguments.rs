@@ -5,7 +5,7 @@ use crate::utils::{
 };
 use proc_macro2::Ident;
 use syn::{
-    Path, Token, Type,
+    LitStr, Path, Token, Type,
     parse::{Parse, ParseStream},
 };
 
@@ -14,6 +14,9 @@ pub struct Arguments {
     pub locales_path: Vec<Path>,
     pub expressions: Vec<Ident>,
     pub expressions_type: Vec<Type>,
+    pub variants: Vec<Ident>,
+    pub tags: Vec<LitStr>,
+    pub fallbacks: Option<Vec<Ident>>,
     pub path: Option<Path>,
     pub locale_name: String,
 }
@@ -24,6 +27,9 @@ impl Parse for Arguments {
         let mut locales_path = None;
         let mut expressions = None;
         let mut expressions_type = None;
+        let mut variants = None;
+        let mut tags = None;
+        let mut fallbacks = None;
         let mut path = None;
         let mut locale_name = None;
 
@@ -41,6 +47,9 @@ impl Parse for Arguments {
                 "expressions_type" => {
                     expressions_type = Some(input.parse_array("expressions_type", Type::parse)?)
                 }
+                "variants" => variants = Some(input.parse_array("variants", Ident::parse)?),
+                "tags" => tags = Some(input.parse_array("tags", <LitStr as Parse>::parse)?),
+                "fallbacks" => fallbacks = Some(input.parse_array("fallbacks", Ident::parse)?),
                 "path" => path = Some(input.parse_path("path")?),
                 "locale_name" => locale_name = Some(input.parse_string("locale_name")?),
                 _ => Err(UnknownArgumentError::new(argument))?,
@@ -55,6 +64,9 @@ impl Parse for Arguments {
             expressions: expressions.ok_or(RequiredArgumentError::new("expressions"))?,
             expressions_type: expressions_type
                 .ok_or(RequiredArgumentError::new("expressions_type"))?,
+            variants: variants.ok_or(RequiredArgumentError::new("variants"))?,
+            tags: tags.ok_or(RequiredArgumentError::new("tags"))?,
+            fallbacks,
             path,
             locale_name: locale_name.ok_or(RequiredArgumentError::new("locale_name"))?,
         })
@@ -14,6 +14,13 @@ pub fn static_expressions(
     let locales_path = &arguments.locales_path;
     let locale_name = &arguments.locale_name;
 
+    let variants = &arguments.variants;
+    let tags = &arguments.tags;
+    let fallbacks = arguments.fallbacks.as_ref().map(|fallbacks| {
+        quote! {
+            fallbacks = [#(#fallbacks),*],
+        }
+    });
     let path_argument = path_argument(arguments.path.clone());
 
     let expressions = arguments
@@ -22,18 +29,20 @@ pub fn static_expressions(
         .zip(&arguments.expressions_type)
         .map(|(name, r#type)| {
             quote! {
-                #localize_it_crate::__expression!(
-                    name = #name,
-                    r#type = #r#type,
-                    locales = [#(#locales),*],
-                    values = [#(#locales_path::#name),*],
-                    #path_argument
-                    locale_name = #locale_name,
-                );
+                #name: #r#type => {
+                    #(#locales: #locales_path::#name),*
+                }
             }
         });
 
     Ok(quote! {
-        #(#expressions)*
+        #localize_it_crate::__expression!(
+            expressions = [#(#expressions),*],
+            variants = [#(#variants),*],
+            tags = [#(#tags),*],
+            #fallbacks
+            #path_argument
+            locale_name = #locale_name,
+        );
     })
 }
@@ -0,0 +1,32 @@
+use crate::{
+    backends::expressions_from_ftl::parser::parse_resource,
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        catalog::{Catalog, CatalogArguments, Resource},
+        combine_errors,
+    },
+};
+use proc_macro::TokenStream;
+use syn::parse;
+
+pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
+    let arguments = &parse::<CatalogArguments>(input)?;
+    let names_provider = &NamesProvider::new(arguments.path.clone());
+
+    let resources = Resource::read_localized(&arguments.file, &arguments.tags)?;
+    let mut catalog = Catalog::new(arguments, resources.iter().collect());
+
+    let mut errors = Vec::new();
+    for (variant, resource) in resources.iter().enumerate() {
+        if let Err(error) = parse_resource(&mut catalog, variant, &arguments.file, resource) {
+            errors.push(error);
+        }
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    Ok(catalog.expand(names_provider)?.into())
+}
@@ -0,0 +1,805 @@
+use crate::utils::{
+    aliases::{SynError, SynResult},
+    catalog::{Catalog, Resource, Translation, identifier},
+    plural_rules::CATEGORIES,
+    template::Piece,
+};
+use syn::LitStr;
+
+const MAX_DEPTH: usize = 32;
+
+struct Entry {
+    id: String,
+    term: bool,
+    line: usize,
+    value: Option<Pattern>,
+    attributes: Vec<(String, Pattern)>,
+}
+
+type Pattern = Vec<Element>;
+
+enum Element {
+    Text(String),
+    Indent(usize),
+    Placeable(Expression),
+}
+
+enum Expression {
+    String(String),
+    Number(String),
+    Variable(String),
+    Message(String, Option<String>),
+    Term(String, Option<String>),
+    Select {
+        selector: Box<Expression>,
+        variants: Vec<Variant>,
+    },
+}
+
+struct Variant {
+    key: String,
+    numeric: bool,
+    default: bool,
+    pattern: Pattern,
+}
+
+struct Parser<'a> {
+    file: &'a LitStr,
+    resource: &'a Resource,
+    chars: Vec<char>,
+    position: usize,
+}
+
+pub fn parse_resource(
+    catalog: &mut Catalog,
+    variant: usize,
+    file: &LitStr,
+    resource: &Resource,
+) -> SynResult<()> {
+    let entries = Parser::new(file, resource).parse_entries()?;
+    let resolver = Resolver {
+        file,
+        resource,
+        entries: &entries,
+    };
+
+    for entry in entries.iter().filter(|entry| !entry.term) {
+        let mut messages = Vec::new();
+        if let Some(value) = &entry.value {
+            messages.push((entry.id.clone(), value));
+        }
+        for (attribute, pattern) in &entry.attributes {
+            messages.push((format!("{}.{attribute}", entry.id), pattern));
+        }
+
+        for (key, pattern) in messages {
+            let translation = resolver.pattern(pattern, entry.line, 0)?;
+
+            catalog.insert(variant, vec![key], translation);
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a> Parser<'a> {
+    fn new(file: &'a LitStr, resource: &'a Resource) -> Self {
+        Self {
+            file,
+            resource,
+            chars: resource.content.chars().collect(),
+            position: 0,
+        }
+    }
+
+    fn parse_entries(mut self) -> SynResult<Vec<Entry>> {
+        let mut entries = Vec::<Entry>::new();
+        while let Some(char) = self.peek() {
+            match char {
+                '\n' => self.position += 1,
+                '#' => self.skip_line(),
+                ' ' | '\t' if self.line_is_blank() => self.skip_line(),
+                '-' | 'a'..='z' | 'A'..='Z' => {
+                    let entry = self.parse_entry()?;
+
+                    if entries
+                        .iter()
+                        .any(|other| other.term == entry.term && other.id == entry.id)
+                    {
+                        Err(self.error_at(entry.line, format!("duplicate entry `{}`", entry.id)))?;
+                    }
+
+                    entries.push(entry);
+                }
+                _ => Err(self.error("expected a message, a term or a comment"))?,
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn parse_entry(&mut self) -> SynResult<Entry> {
+        let line = self.line();
+        let term = self.eat('-');
+        let id = self.parse_identifier()?;
+
+        self.skip_inline_blank();
+        self.expect('=')?;
+        self.skip_inline_blank();
+
+        let value = self.parse_pattern()?;
+        self.expect_line_end()?;
+
+        let mut attributes = Vec::new();
+        while let Some(position) = self.attribute_start() {
+            self.position = position + 1;
+
+            let attribute = self.parse_identifier()?;
+            self.skip_inline_blank();
+            self.expect('=')?;
+            self.skip_inline_blank();
+
+            let pattern = self.parse_pattern()?;
+            if pattern.is_empty() {
+                Err(self.error(format!("attribute `{attribute}` of `{id}` has no value")))?;
+            }
+            self.expect_line_end()?;
+
+            attributes.push((attribute, pattern));
+        }
+
+        if value.is_empty() && (term || attributes.is_empty()) {
+            Err(self.error_at(line, format!("`{id}` has no value")))?;
+        }
+
+        Ok(Entry {
+            id,
+            term,
+            line,
+            value: (!value.is_empty()).then_some(value),
+            attributes,
+        })
+    }
+
+    fn parse_pattern(&mut self) -> SynResult<Pattern> {
+        let mut elements = Vec::new();
+        let mut text = String::new();
+        while let Some(char) = self.peek() {
+            match char {
+                '{' => {
+                    self.position += 1;
+
+                    push_text(&mut elements, &mut text);
+                    elements.push(Element::Placeable(self.parse_placeable()?));
+                }
+                '}' => break,
+                '\n' => {
+                    let Some((blank_lines, indent, position)) = self.continuation() else {
+                        break;
+                    };
+                    self.position = position;
+
+                    text.extend(std::iter::repeat_n('\n', blank_lines + 1));
+                    push_text(&mut elements, &mut text);
+                    elements.push(Element::Indent(indent));
+                }
+                char => {
+                    self.position += 1;
+
+                    text.push(char);
+                }
+            }
+        }
+        push_text(&mut elements, &mut text);
+
+        Ok(finish_pattern(elements))
+    }
+
+    fn parse_placeable(&mut self) -> SynResult<Expression> {
+        self.skip_blank();
+        let expression = self.parse_inline_expression()?;
+        self.skip_blank();
+
+        if self.eat_str("->") {
+            let variants = self.parse_variants()?;
+            self.skip_blank();
+            self.expect('}')?;
+
+            return Ok(Expression::Select {
+                selector: Box::new(expression),
+                variants,
+            });
+        }
+
+        self.expect('}')?;
+
+        Ok(expression)
+    }
+
+    fn parse_inline_expression(&mut self) -> SynResult<Expression> {
+        match (self.peek(), self.peek_nth(1)) {
+            (Some('"'), _) => self.parse_string(),
+            (Some('0'..='9'), _) | (Some('-'), Some('0'..='9')) => {
+                Ok(Expression::Number(self.parse_number()))
+            }
+            (Some('$'), _) => {
+                self.position += 1;
+
+                Ok(Expression::Variable(self.parse_identifier()?))
+            }
+            (Some('-'), _) => {
+                self.position += 1;
+
+                let id = self.parse_identifier()?;
+                let attribute = self.parse_attribute_accessor()?;
+                if self.peek() == Some('(') {
+                    Err(self.error(format!("arguments of term `-{id}` are not supported")))?;
+                }
+
+                Ok(Expression::Term(id, attribute))
+            }
+            (Some('a'..='z' | 'A'..='Z'), _) => {
+                let id = self.parse_identifier()?;
+                if self.peek() == Some('(') {
+                    Err(self.error(format!("function `{id}` is not supported")))?;
+                }
+                let attribute = self.parse_attribute_accessor()?;
+
+                Ok(Expression::Message(id, attribute))
+            }
+            (Some('{'), _) => Err(self.error("nested placeables are not supported")),
+            _ => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn parse_attribute_accessor(&mut self) -> SynResult<Option<String>> {
+        Ok(if self.eat('.') {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        })
+    }
+
+    fn parse_variants(&mut self) -> SynResult<Vec<Variant>> {
+        let line = self.line();
+
+        let mut variants = Vec::new();
+        loop {
+            self.skip_blank();
+
+            let default = self.eat('*');
+            if !self.eat('[') {
+                if default {
+                    Err(self.error("expected `[` after `*`"))?;
+                }
+
+                break;
+            }
+
+            self.skip_inline_blank();
+            let numeric = matches!(self.peek(), Some('0'..='9' | '-'));
+            let key = if numeric {
+                self.parse_number()
+            } else {
+                self.parse_identifier()?
+            };
+            self.skip_inline_blank();
+            self.expect(']')?;
+            self.skip_inline_blank();
+
+            variants.push(Variant {
+                key,
+                numeric,
+                default,
+                pattern: self.parse_pattern()?,
+            });
+        }
+
+        if variants.iter().filter(|variant| variant.default).count() != 1 {
+            Err(self.error_at(
+                line,
+                "a select expression must have exactly one default variant `*[...]`",
+            ))?;
+        }
+
+        Ok(variants)
+    }
+
+    fn parse_string(&mut self) -> SynResult<Expression> {
+        self.position += 1;
+
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('"') => break,
+                Some('\\') => match self.next() {
+                    Some(char @ ('"' | '\\')) => string.push(char),
+                    Some(char @ ('u' | 'U')) => {
+                        let length = if char == 'u' { 4 } else { 6 };
+                        let digits = (0..length).filter_map(|_| self.next()).collect::<String>();
+
+                        match u32::from_str_radix(&digits, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                        {
+                            Some(char) => string.push(char),
+                            None => Err(self.error(format!("invalid escape `\\{char}{digits}`")))?,
+                        }
+                    }
+                    _ => Err(self.error("invalid escape in a string literal"))?,
+                },
+                Some('\n') | None => Err(self.error("unterminated string literal"))?,
+                Some(char) => string.push(char),
+            }
+        }
+
+        Ok(Expression::String(string))
+    }
+
+    fn parse_number(&mut self) -> String {
+        let start = self.position;
+        self.eat('-');
+        while matches!(self.peek(), Some('0'..='9' | '.')) {
+            self.position += 1;
+        }
+
+        self.chars[start..self.position].iter().collect()
+    }
+
+    fn parse_identifier(&mut self) -> SynResult<String> {
+        if !matches!(self.peek(), Some('a'..='z' | 'A'..='Z')) {
+            Err(self.error("expected an identifier"))?;
+        }
+
+        let start = self.position;
+        while matches!(
+            self.peek(),
+            Some('a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-')
+        ) {
+            self.position += 1;
+        }
+
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn continuation(&self) -> Option<(usize, usize, usize)> {
+        let mut position = self.position + 1;
+        let mut blank_lines = 0;
+        loop {
+            let indent = self.indent(position);
+            match self.chars.get(position + indent) {
+                Some('\n') => {
+                    blank_lines += 1;
+                    position += indent + 1;
+                }
+                Some('[' | '*' | '.' | '}') | None => return None,
+                Some(_) if indent == 0 => return None,
+                Some(_) => return Some((blank_lines, indent, position + indent)),
+            }
+        }
+    }
+
+    fn attribute_start(&self) -> Option<usize> {
+        let mut position = self.position;
+        while self.chars.get(position) == Some(&'\n') {
+            position += 1;
+
+            let indent = self.indent(position);
+            match self.chars.get(position + indent) {
+                Some('.') if indent > 0 => return Some(position + indent),
+                Some('\n') => position += indent,
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    fn indent(&self, position: usize) -> usize {
+        self.chars[position.min(self.chars.len())..]
+            .iter()
+            .take_while(|char| **char == ' ')
+            .count()
+    }
+
+    fn expect_line_end(&self) -> SynResult<()> {
+        match self.peek() {
+            Some('\n') | None => Ok(()),
+            Some(char) => Err(self.error(format!("unexpected `{char}`"))),
+        }
+    }
+
+    fn line_is_blank(&self) -> bool {
+        self.chars[self.position..]
+            .iter()
+            .take_while(|char| **char != '\n')
+            .all(|char| char.is_whitespace())
+    }
+
+    fn skip_line(&mut self) {
+        while self.next().is_some_and(|char| char != '\n') {}
+    }
+
+    fn skip_inline_blank(&mut self) {
+        while self.peek() == Some(' ') {
+            self.position += 1;
+        }
+    }
+
+    fn skip_blank(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\n')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> SynResult<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{expected}`")))
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.position += 1;
+        }
+
+        matched
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let matched = expected
+            .chars()
+            .enumerate()
+            .all(|(i, char)| self.peek_nth(i) == Some(char));
+        if matched {
+            self.position += expected.chars().count();
+        }
+
+        matched
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.chars.get(self.position + n).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek();
+        self.position += 1;
+
+        char
+    }
+
+    fn line(&self) -> usize {
+        self.chars[..self.position.min(self.chars.len())]
+            .iter()
+            .filter(|char| **char == '\n')
+            .count()
+            + 1
+    }
+
+    fn error(&self, message: impl Into<String>) -> SynError {
+        self.error_at(self.line(), message)
+    }
+
+    fn error_at(&self, line: usize, message: impl Into<String>) -> SynError {
+        self.resource.error(self.file, line, message.into())
+    }
+}
+
+struct Resolver<'a> {
+    file: &'a LitStr,
+    resource: &'a Resource,
+    entries: &'a [Entry],
+}
+
+impl Resolver<'_> {
+    fn pattern(&self, pattern: &Pattern, line: usize, depth: usize) -> SynResult<Translation> {
+        let mut translation = Translation::Text(Vec::new());
+        for element in pattern {
+            let part = match element {
+                Element::Text(text) => Translation::Text(vec![Piece::Text(text.clone())]),
+                Element::Indent(_) => continue,
+                Element::Placeable(expression) => self.expression(expression, line, depth)?,
+            };
+
+            translation = self.concat(translation, part, line)?;
+        }
+
+        Ok(translation)
+    }
+
+    fn expression(
+        &self,
+        expression: &Expression,
+        line: usize,
+        depth: usize,
+    ) -> SynResult<Translation> {
+        Ok(match expression {
+            Expression::String(value) | Expression::Number(value) => {
+                Translation::Text(vec![Piece::Text(value.clone())])
+            }
+            Expression::Variable(name) => {
+                Translation::Text(vec![Piece::Placeholder(identifier(name))])
+            }
+            Expression::Message(id, attribute) => {
+                self.reference(false, id, attribute.as_deref(), line, depth)?
+            }
+            Expression::Term(id, attribute) => {
+                self.reference(true, id, attribute.as_deref(), line, depth)?
+            }
+            Expression::Select { selector, variants } => {
+                if let Expression::Variable(name) = selector.as_ref() {
+                    self.plural(name, variants, line, depth)?
+                } else {
+                    let Translation::Text(pieces) = self.expression(selector, line, depth)? else {
+                        Err(self.error(line, "nested selections are not supported"))?
+                    };
+
+                    let mut value = String::new();
+                    for piece in pieces {
+                        match piece {
+                            Piece::Text(text) => value.push_str(&text),
+                            Piece::Placeholder(_) => Err(self.error(
+                                line,
+                                "a selector must be a variable or resolve to a literal",
+                            ))?,
+                        }
+                    }
+
+                    let variant = variants
+                        .iter()
+                        .find(|variant| variant.key == value)
+                        .or(variants.iter().find(|variant| variant.default))
+                        .expect("select expressions have a default variant");
+
+                    self.pattern(&variant.pattern, line, depth)?
+                }
+            }
+        })
+    }
+
+    fn plural(
+        &self,
+        name: &str,
+        variants: &[Variant],
+        line: usize,
+        depth: usize,
+    ) -> SynResult<Translation> {
+        let mut categories = Vec::new();
+        let mut default = 0;
+        for variant in variants {
+            if variant.numeric {
+                Err(self.error(
+                    line,
+                    format!("numeric variant key `[{}]` is not supported", variant.key),
+                ))?;
+            }
+            if !CATEGORIES.contains(&variant.key.as_str()) {
+                Err(self.error(
+                    line,
+                    format!(
+                        "a selection on `${name}` must use plural categories as keys, found `[{}]`",
+                        variant.key
+                    ),
+                ))?;
+            }
+
+            let Translation::Text(pieces) = self.pattern(&variant.pattern, line, depth)? else {
+                Err(self.error(line, "nested selections are not supported"))?
+            };
+
+            if variant.default {
+                default = categories.len();
+            }
+            categories.push((variant.key.clone(), pieces));
+        }
+
+        Ok(Translation::Plural {
+            selector: identifier(name),
+            categories,
+            default,
+        })
+    }
+
+    fn reference(
+        &self,
+        term: bool,
+        id: &str,
+        attribute: Option<&str>,
+        line: usize,
+        depth: usize,
+    ) -> SynResult<Translation> {
+        let prefix = if term { "-" } else { "" };
+        if depth >= MAX_DEPTH {
+            Err(self.error(line, format!("`{prefix}{id}` references itself")))?;
+        }
+
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.term == term && entry.id == id)
+            .ok_or_else(|| self.error(line, format!("unknown reference `{prefix}{id}`")))?;
+        let pattern = match attribute {
+            Some(attribute) => entry
+                .attributes
+                .iter()
+                .find(|(name, _)| name == attribute)
+                .map(|(_, pattern)| pattern)
+                .ok_or_else(|| {
+                    self.error(
+                        line,
+                        format!("unknown attribute `{prefix}{id}.{attribute}`"),
+                    )
+                })?,
+            None => entry
+                .value
+                .as_ref()
+                .ok_or_else(|| self.error(line, format!("`{prefix}{id}` has no value")))?,
+        };
+
+        self.pattern(pattern, line, depth + 1)
+    }
+
+    fn concat(&self, left: Translation, right: Translation, line: usize) -> SynResult<Translation> {
+        Ok(match (left, right) {
+            (Translation::Text(mut left), Translation::Text(right)) => {
+                right
+                    .into_iter()
+                    .for_each(|piece| push_piece(&mut left, piece));
+
+                Translation::Text(left)
+            }
+            (
+                Translation::Plural {
+                    selector,
+                    mut categories,
+                    default,
+                },
+                Translation::Text(right),
+            ) => {
+                for (_, pieces) in &mut categories {
+                    right
+                        .iter()
+                        .for_each(|piece| push_piece(pieces, piece.clone()));
+                }
+
+                Translation::Plural {
+                    selector,
+                    categories,
+                    default,
+                }
+            }
+            (
+                Translation::Text(left),
+                Translation::Plural {
+                    selector,
+                    categories,
+                    default,
+                },
+            ) => Translation::Plural {
+                selector,
+                categories: categories
+                    .into_iter()
+                    .map(|(category, right)| {
+                        let mut pieces = left.clone();
+                        right
+                            .into_iter()
+                            .for_each(|piece| push_piece(&mut pieces, piece));
+
+                        (category, pieces)
+                    })
+                    .collect(),
+                default,
+            },
+            (
+                Translation::Plural {
+                    selector,
+                    categories: left,
+                    default: left_default,
+                },
+                Translation::Plural {
+                    selector: right_selector,
+                    categories: right,
+                    default: right_default,
+                },
+            ) => {
+                if selector != right_selector {
+                    Err(self.error(
+                        line,
+                        format!(
+                            "a message can select on only one variable, found `${selector}` and `${right_selector}`"
+                        ),
+                    ))?;
+                }
+
+                let find = |categories: &[(String, Vec<Piece>)], category: &str, default: usize| {
+                    categories
+                        .iter()
+                        .find(|(name, _)| name == category)
+                        .map_or(categories[default].1.clone(), |(_, pieces)| pieces.clone())
+                };
+                let mut names = left
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect::<Vec<_>>();
+                for (name, _) in &right {
+                    if !names.contains(name) {
+                        names.push(name.clone());
+                    }
+                }
+
+                let categories = names
+                    .into_iter()
+                    .map(|name| {
+                        let mut pieces = find(&left, &name, left_default);
+                        find(&right, &name, right_default)
+                            .into_iter()
+                            .for_each(|piece| push_piece(&mut pieces, piece));
+
+                        (name, pieces)
+                    })
+                    .collect();
+
+                Translation::Plural {
+                    selector,
+                    categories,
+                    default: left_default,
+                }
+            }
+        })
+    }
+
+    fn error(&self, line: usize, message: impl Into<String>) -> SynError {
+        self.resource.error(self.file, line, message.into())
+    }
+}
+
+fn push_text(elements: &mut Pattern, text: &mut String) {
+    if !text.is_empty() {
+        elements.push(Element::Text(std::mem::take(text)));
+    }
+}
+
+fn push_piece(pieces: &mut Vec<Piece>, piece: Piece) {
+    match (pieces.last_mut(), piece) {
+        (Some(Piece::Text(last)), Piece::Text(text)) => last.push_str(&text),
+        (_, piece) => pieces.push(piece),
+    }
+}
+
+fn finish_pattern(elements: Pattern) -> Pattern {
+    let common_indent = elements
+        .iter()
+        .filter_map(|element| match element {
+            Element::Indent(indent) => Some(*indent),
+            _ => None,
+        })
+        .min()
+        .unwrap_or_default();
+
+    let mut elements = elements
+        .into_iter()
+        .map(|element| match element {
+            Element::Indent(indent) => Element::Text(" ".repeat(indent - common_indent)),
+            element => element,
+        })
+        .filter(|element| !matches!(element, Element::Text(text) if text.is_empty()))
+        .collect::<Vec<_>>();
+
+    if let Some(Element::Text(text)) = elements.first_mut() {
+        *text = text.trim_start_matches('\n').to_string();
+    }
+    if let Some(Element::Text(text)) = elements.last_mut() {
+        *text = text.trim_end().to_string();
+    }
+    elements.retain(|element| !matches!(element, Element::Text(text) if text.is_empty()));
+
+    elements
+}
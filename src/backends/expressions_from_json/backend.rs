@@ -0,0 +1,87 @@
+use crate::{
+    backends::expressions_from_json::parser::{Key, Shape, parse_resource},
+    utils::{
+        NamesProvider,
+        aliases::{SynError, SynResult},
+        catalog::{Catalog, CatalogArguments, Resource},
+        combine_errors,
+    },
+};
+use proc_macro::TokenStream;
+use syn::parse;
+
+pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
+    let arguments = &parse::<CatalogArguments>(input)?;
+    let names_provider = &NamesProvider::new(arguments.path.clone());
+
+    let resources = Resource::read_localized(&arguments.file, &arguments.tags)?;
+    let mut catalog = Catalog::new(arguments, resources.iter().collect());
+
+    let mut keys = Vec::new();
+    let mut errors = Vec::new();
+    for (variant, resource) in resources.iter().enumerate() {
+        match parse_resource(&mut catalog, variant, &arguments.file, resource) {
+            Ok(variant_keys) => keys.push(variant_keys),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    let default = arguments
+        .variants
+        .iter()
+        .position(|variant| *variant == arguments.default)
+        .unwrap_or_default();
+    if let Some(error) = combine_errors(check_shapes(arguments, &resources, &keys, default)) {
+        Err(error)?;
+    }
+
+    Ok(catalog.expand(names_provider)?.into())
+}
+
+fn check_shapes(
+    arguments: &CatalogArguments,
+    resources: &[Resource],
+    keys: &[Vec<Key>],
+    default: usize,
+) -> Vec<SynError> {
+    let shape_name = |shape: &Shape| match shape {
+        Shape::Object => "an object",
+        Shape::Message => "a message",
+    };
+
+    let mut errors = Vec::new();
+    for (variant, variant_keys) in keys.iter().enumerate() {
+        if variant == default {
+            continue;
+        }
+
+        for key in variant_keys {
+            let Some(expected) = keys[default]
+                .iter()
+                .find(|expected| expected.key == key.key)
+            else {
+                continue;
+            };
+
+            if expected.shape != key.shape {
+                errors.push(resources[variant].error(
+                    &arguments.file,
+                    key.line,
+                    format!(
+                        "`{}` is {}, but {} in `{}`",
+                        key.key.join("."),
+                        shape_name(&key.shape),
+                        shape_name(&expected.shape),
+                        resources[default].path,
+                    ),
+                ));
+            }
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,350 @@
+use crate::utils::{
+    aliases::{SynError, SynResult},
+    catalog::{Catalog, Resource, Translation},
+    plural_rules::CATEGORIES,
+    template::{Piece, parse_pieces},
+};
+use syn::LitStr;
+
+enum Value {
+    String(String),
+    Object(Vec<(String, Node)>),
+    Other(&'static str),
+}
+
+struct Node {
+    line: usize,
+    value: Value,
+}
+
+#[derive(PartialEq)]
+pub enum Shape {
+    Object,
+    Message,
+}
+
+pub struct Key {
+    pub key: Vec<String>,
+    pub shape: Shape,
+    pub line: usize,
+}
+
+struct Parser<'a> {
+    file: &'a LitStr,
+    resource: &'a Resource,
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+}
+
+pub fn parse_resource(
+    catalog: &mut Catalog,
+    variant: usize,
+    file: &LitStr,
+    resource: &Resource,
+) -> SynResult<Vec<Key>> {
+    let mut parser = Parser {
+        file,
+        resource,
+        chars: resource.content.chars().collect(),
+        position: 0,
+        line: 1,
+    };
+
+    let root = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        Err(parser.error("unexpected content after the root object"))?;
+    }
+
+    let Value::Object(members) = root.value else {
+        Err(parser.error_at(root.line, "the root must be an object"))?
+    };
+
+    let mut keys = Vec::new();
+    parser.collect(catalog, variant, &mut Vec::new(), members, &mut keys)?;
+
+    Ok(keys)
+}
+
+impl Parser<'_> {
+    fn collect(
+        &self,
+        catalog: &mut Catalog,
+        variant: usize,
+        path: &mut Vec<String>,
+        members: Vec<(String, Node)>,
+        keys: &mut Vec<Key>,
+    ) -> SynResult<()> {
+        for (name, node) in members {
+            path.push(name);
+            let key = path.join(".");
+
+            match node.value {
+                Value::String(template) => {
+                    keys.push(Key {
+                        key: path.clone(),
+                        shape: Shape::Message,
+                        line: node.line,
+                    });
+
+                    let pieces = self.pieces(&template, &key, node.line)?;
+                    catalog.insert(variant, path.clone(), Translation::Text(pieces));
+                }
+                Value::Object(members) if is_plural(&members) => {
+                    keys.push(Key {
+                        key: path.clone(),
+                        shape: Shape::Message,
+                        line: node.line,
+                    });
+
+                    let mut categories = Vec::new();
+                    for (category, node) in members {
+                        if let Value::String(template) = node.value {
+                            categories.push((category, self.pieces(&template, &key, node.line)?));
+                        }
+                    }
+
+                    let default = categories
+                        .iter()
+                        .position(|(category, _)| category == "other")
+                        .unwrap_or_default();
+                    let selector = categories[default]
+                        .1
+                        .iter()
+                        .find_map(|piece| match piece {
+                            Piece::Placeholder(placeholder) => Some(placeholder.clone()),
+                            Piece::Text(_) => None,
+                        })
+                        .unwrap_or("n".to_string());
+
+                    catalog.insert(
+                        variant,
+                        path.clone(),
+                        Translation::Plural {
+                            selector,
+                            categories,
+                            default,
+                        },
+                    );
+                }
+                Value::Object(members) => {
+                    keys.push(Key {
+                        key: path.clone(),
+                        shape: Shape::Object,
+                        line: node.line,
+                    });
+
+                    self.collect(catalog, variant, path, members, keys)?;
+                }
+                Value::Other(kind) => Err(self.error_at(
+                    node.line,
+                    format!("`{key}` must be a string or an object, found {kind}"),
+                ))?,
+            }
+
+            path.pop();
+        }
+
+        Ok(())
+    }
+
+    fn pieces(&self, template: &str, key: &str, line: usize) -> SynResult<Vec<Piece>> {
+        parse_pieces(template)
+            .ok_or_else(|| self.error_at(line, format!("invalid template of `{key}`")))
+    }
+
+    fn parse_value(&mut self) -> SynResult<Node> {
+        self.skip_whitespace();
+        let line = self.line;
+
+        let value = match self.peek() {
+            Some('{') => Value::Object(self.parse_object()?),
+            Some('"') => Value::String(self.parse_string()?),
+            Some('[') => {
+                self.position += 1;
+                self.skip_whitespace();
+
+                if !self.eat(']') {
+                    loop {
+                        self.parse_value()?;
+                        self.skip_whitespace();
+
+                        if self.eat(']') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+
+                Value::Other("an array")
+            }
+            Some('-' | '0'..='9') => {
+                while matches!(self.peek(), Some('-' | '+' | '.' | 'e' | 'E' | '0'..='9')) {
+                    self.position += 1;
+                }
+
+                Value::Other("a number")
+            }
+            Some('t' | 'f') => {
+                if !self.eat_str("true") && !self.eat_str("false") {
+                    Err(self.error("expected a value"))?;
+                }
+
+                Value::Other("a boolean")
+            }
+            Some('n') => {
+                if !self.eat_str("null") {
+                    Err(self.error("expected a value"))?;
+                }
+
+                Value::Other("null")
+            }
+            _ => Err(self.error("expected a value"))?,
+        };
+
+        Ok(Node { line, value })
+    }
+
+    fn parse_object(&mut self) -> SynResult<Vec<(String, Node)>> {
+        self.position += 1;
+        self.skip_whitespace();
+
+        let mut members = Vec::<(String, Node)>::new();
+        if self.eat('}') {
+            return Ok(members);
+        }
+
+        loop {
+            self.skip_whitespace();
+            let line = self.line;
+            if self.peek() != Some('"') {
+                Err(self.error("expected a key"))?;
+            }
+            let name = self.parse_string()?;
+            if members.iter().any(|(other, _)| *other == name) {
+                Err(self.error_at(line, format!("duplicate key `{name}`")))?;
+            }
+
+            self.skip_whitespace();
+            self.expect(':')?;
+            members.push((name, self.parse_value()?));
+            self.skip_whitespace();
+
+            if self.eat('}') {
+                break;
+            }
+            self.expect(',')?;
+        }
+
+        Ok(members)
+    }
+
+    fn parse_string(&mut self) -> SynResult<String> {
+        self.position += 1;
+
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('"') => break,
+                Some('\\') => match self.next() {
+                    Some('"') => string.push('"'),
+                    Some('\\') => string.push('\\'),
+                    Some('/') => string.push('/'),
+                    Some('b') => string.push('\u{8}'),
+                    Some('f') => string.push('\u{c}'),
+                    Some('n') => string.push('\n'),
+                    Some('r') => string.push('\r'),
+                    Some('t') => string.push('\t'),
+                    Some('u') => {
+                        let mut code = self.parse_code_unit()?;
+                        if (0xD800..0xDC00).contains(&code) && self.eat_str("\\u") {
+                            let low = self.parse_code_unit()?;
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00));
+                        }
+
+                        string.push(
+                            char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?,
+                        );
+                    }
+                    _ => Err(self.error("invalid escape"))?,
+                },
+                Some('\n') | None => Err(self.error("unterminated string"))?,
+                Some(char) => string.push(char),
+            }
+        }
+
+        Ok(string)
+    }
+
+    fn parse_code_unit(&mut self) -> SynResult<u32> {
+        let digits = (0..4).filter_map(|_| self.next()).collect::<String>();
+
+        u32::from_str_radix(&digits, 16).map_err(|_| self.error("invalid unicode escape"))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(char @ (' ' | '\t' | '\r' | '\n')) = self.peek() {
+            if char == '\n' {
+                self.line += 1;
+            }
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> SynResult<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{expected}`")))
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.position += 1;
+        }
+
+        matched
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let matched = expected
+            .chars()
+            .enumerate()
+            .all(|(i, char)| self.chars.get(self.position + i) == Some(&char));
+        if matched {
+            self.position += expected.chars().count();
+        }
+
+        matched
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek();
+        self.position += 1;
+
+        char
+    }
+
+    fn error(&self, message: impl Into<String>) -> SynError {
+        self.error_at(self.line, message)
+    }
+
+    fn error_at(&self, line: usize, message: impl Into<String>) -> SynError {
+        self.resource.error(self.file, line, message.into())
+    }
+}
+
+fn is_plural(members: &[(String, Node)]) -> bool {
+    members.iter().any(|(name, _)| name == "other")
+        && members.iter().all(|(name, node)| {
+            CATEGORIES.contains(&name.as_str()) && matches!(node.value, Value::String(_))
+        })
+}
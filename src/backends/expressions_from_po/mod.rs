@@ -0,0 +1,5 @@
+mod backend;
+mod parser;
+mod plural_forms;
+
+pub use backend::backend as expressions_from_po_backend;
@@ -0,0 +1,294 @@
+use crate::{
+    backends::expressions_from_po::plural_forms::PluralForms,
+    utils::{
+        aliases::SynResult,
+        catalog::{Catalog, CatalogArguments, Resource, Translation},
+        language_tag::LanguageTag,
+        plural_rules::PluralRules,
+        template::{Piece, parse_pieces},
+    },
+};
+
+#[derive(Default)]
+struct Entry {
+    line: usize,
+    fuzzy: bool,
+    notes: Vec<String>,
+    context: Option<String>,
+    id: Option<String>,
+    id_plural: Option<String>,
+    strings: Vec<(usize, String)>,
+}
+
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    String(usize),
+}
+
+pub fn parse_resource(
+    catalog: &mut Catalog,
+    arguments: &CatalogArguments,
+    variant: usize,
+    resource: &Resource,
+) -> SynResult<()> {
+    let default = arguments.variants[variant] == arguments.default;
+    let [language, ..] = LanguageTag::matching_subtags(&arguments.tags[variant]);
+    let entries = parse_entries(arguments, resource)?;
+
+    let header = entries
+        .iter()
+        .find(|entry| entry.id.as_deref() == Some("") && entry.context.is_none());
+    let plural_forms = header.and_then(|header| {
+        let error = |message: String| resource.error(&arguments.file, header.line, message);
+        let value = header
+            .strings
+            .first()?
+            .1
+            .lines()
+            .find_map(|line| line.strip_prefix("Plural-Forms:"))?;
+
+        Some(
+            PluralForms::parse(value)
+                .ok_or_else(|| error(format!("invalid `Plural-Forms` header `{}`", value.trim())))
+                .and_then(|plural_forms| {
+                    let rules = PluralRules::new(&language).ok_or_else(|| {
+                        error(format!("no CLDR plural rules for language `{language}`"))
+                    })?;
+                    let categories = plural_forms.categories(&rules).map_err(error)?;
+
+                    Ok((plural_forms.count(), categories))
+                }),
+        )
+    });
+
+    for entry in entries {
+        let error = |message: String| resource.error(&arguments.file, entry.line, message);
+        let Some(id) = &entry.id else {
+            Err(error("an entry without `msgid`".to_string()))?
+        };
+        if id.is_empty() && entry.context.is_none() {
+            continue;
+        }
+
+        let translated = !entry.fuzzy && entry.strings.iter().any(|(_, string)| !string.is_empty());
+        if !translated && !default {
+            continue;
+        }
+
+        let pieces = |template: &str| {
+            parse_pieces(template).ok_or_else(|| error(format!("invalid template `{template}`")))
+        };
+
+        let translation = match &entry.id_plural {
+            Some(id_plural) => {
+                let mut translation_categories = Vec::new();
+                let mut default_category = 0;
+                if translated {
+                    let (count, categories) = plural_forms.clone().unwrap_or_else(|| {
+                        Err(error(
+                            "plural entries require a `Plural-Forms` header".to_string(),
+                        ))
+                    })?;
+
+                    if let Some((index, _)) =
+                        entry.strings.iter().find(|(index, _)| *index >= count)
+                    {
+                        Err(error(format!(
+                            "`msgstr[{index}]`, but `Plural-Forms` has {count} forms"
+                        )))?;
+                    }
+
+                    let mut last_form = 0;
+                    for (category, form) in categories {
+                        let Some((_, string)) =
+                            entry.strings.iter().find(|(index, _)| *index == form)
+                        else {
+                            continue;
+                        };
+
+                        if form >= last_form {
+                            last_form = form;
+                            default_category = translation_categories.len();
+                        }
+                        translation_categories.push((category.to_string(), pieces(string)?));
+                    }
+                } else {
+                    for (category, string) in [("one", id), ("other", id_plural)] {
+                        translation_categories.push((category.to_string(), pieces(string)?));
+                    }
+                    default_category = 1;
+                }
+
+                if translation_categories.is_empty() {
+                    Err(error("no plural forms".to_string()))?;
+                }
+
+                let selector = pieces(id_plural)?
+                    .into_iter()
+                    .find_map(|piece| match piece {
+                        Piece::Placeholder(placeholder) => Some(placeholder),
+                        Piece::Text(_) => None,
+                    })
+                    .unwrap_or("n".to_string());
+
+                Translation::Plural {
+                    selector,
+                    default: default_category,
+                    categories: translation_categories,
+                }
+            }
+            None => {
+                if entry.strings.len() > 1 {
+                    Err(error("plural forms without `msgid_plural`".to_string()))?;
+                }
+
+                let string = if translated { &entry.strings[0].1 } else { id };
+
+                Translation::Text(pieces(string)?)
+            }
+        };
+
+        let key = match &entry.context {
+            Some(context) => context.split('.').map(str::to_string).collect(),
+            None => vec![id.clone()],
+        };
+        let name = key.join(".");
+        if default && !entry.notes.is_empty() {
+            catalog.note(key.clone(), entry.notes.join("\n"));
+        }
+        if catalog.insert(variant, key, translation).is_some() {
+            Err(error(format!("duplicate entry `{name}`")))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_entries(arguments: &CatalogArguments, resource: &Resource) -> SynResult<Vec<Entry>> {
+    let mut entries = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = None;
+    for (i, line) in resource.content.lines().enumerate() {
+        let line_number = i + 1;
+        let error = |message: String| resource.error(&arguments.file, line_number, message);
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("#~") {
+            continue;
+        }
+
+        if (line.starts_with('#') || line.starts_with("msgctxt") || line.starts_with("msgid "))
+            && entry.id.is_some()
+        {
+            entries.push(std::mem::take(&mut entry));
+            field = None;
+        }
+
+        if let Some(note) = line.strip_prefix("#.") {
+            entry
+                .notes
+                .push(note.strip_prefix(' ').unwrap_or(note).to_string());
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let string =
+                parse_string(line).ok_or_else(|| error(format!("invalid string {line}")))?;
+            let target = match field {
+                Some(Field::Context) => entry.context.as_mut(),
+                Some(Field::Id) => entry.id.as_mut(),
+                Some(Field::IdPlural) => entry.id_plural.as_mut(),
+                Some(Field::String(index)) => entry
+                    .strings
+                    .iter_mut()
+                    .find(|(string_index, _)| *string_index == index)
+                    .map(|(_, string)| string),
+                None => None,
+            };
+
+            target
+                .ok_or_else(|| error("a string without a keyword".to_string()))?
+                .push_str(&string);
+            continue;
+        }
+
+        let (keyword, value) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| error(format!("expected a keyword and a string, found `{line}`")))?;
+        let value = parse_string(value.trim())
+            .ok_or_else(|| error(format!("invalid string {}", value.trim())))?;
+
+        if entry.line == 0 {
+            entry.line = line_number;
+        }
+
+        field = Some(match keyword {
+            "msgctxt" => {
+                entry.context = Some(value);
+
+                Field::Context
+            }
+            "msgid" => {
+                entry.id = Some(value);
+
+                Field::Id
+            }
+            "msgid_plural" => {
+                entry.id_plural = Some(value);
+
+                Field::IdPlural
+            }
+            "msgstr" => {
+                entry.strings.push((0, value));
+
+                Field::String(0)
+            }
+            _ => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|index| index.strip_suffix(']'))
+                    .and_then(|index| index.parse().ok())
+                    .ok_or_else(|| error(format!("unknown keyword `{keyword}`")))?;
+                entry.strings.push((index, value));
+
+                Field::String(index)
+            }
+        });
+    }
+
+    if entry.id.is_some() || entry.context.is_some() {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn parse_string(string: &str) -> Option<String> {
+    let mut chars = string.strip_prefix('"')?.strip_suffix('"')?.chars();
+
+    let mut value = String::new();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                char @ ('"' | '\\') => char,
+                _ => None?,
+            }),
+            '"' => None?,
+            char => value.push(char),
+        }
+    }
+
+    Some(value)
+}
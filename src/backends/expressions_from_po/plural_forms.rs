@@ -0,0 +1,303 @@
+use crate::utils::plural_rules::{CATEGORIES, PluralRules};
+
+const SAMPLES: [u64; 4] = [1_000_000, 2_000_000, 10_000_000, 1_000_000_000];
+
+pub struct PluralForms {
+    count: usize,
+    formula: Expression,
+}
+
+enum Expression {
+    Number(u64),
+    N,
+    Not(Box<Expression>),
+    Binary(&'static str, Box<Expression>, Box<Expression>),
+    Condition(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl PluralForms {
+    pub fn parse(header: &str) -> Option<Self> {
+        let mut count = None;
+        let mut formula = None;
+        for field in header.split(';') {
+            match field.split_once('=') {
+                Some((name, value)) if name.trim() == "nplurals" => {
+                    count = value.trim().parse().ok().filter(|count| *count > 0)
+                }
+                Some((name, value)) if name.trim() == "plural" => formula = Some(value),
+                None if field.trim().is_empty() => {}
+                _ => None?,
+            }
+        }
+
+        Some(Self {
+            count: count?,
+            formula: Parser::parse(formula?)?,
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn categories(&self, rules: &PluralRules) -> Result<Vec<(&'static str, usize)>, String> {
+        let mut categories = Vec::<(&str, usize, u64)>::new();
+        for number in (0..=1000).chain(SAMPLES) {
+            let category = rules.integer_category(number);
+            let form = self.formula.evaluate(number);
+            let form = usize::try_from(form)
+                .ok()
+                .filter(|form| *form < self.count)
+                .ok_or_else(|| {
+                    format!(
+                        "`Plural-Forms` selects form {form} for {number}, but `nplurals` is {}",
+                        self.count
+                    )
+                })?;
+
+            match categories.iter().find(|(other, ..)| *other == category) {
+                Some((_, other_form, _)) if *other_form == form => {}
+                Some((_, other_form, other_number)) => Err(format!(
+                    "`Plural-Forms` is ambiguous for the CLDR category `{category}`: \
+                    {other_number} selects form {other_form}, but {number} selects form {form}"
+                ))?,
+                None => categories.push((category, form, number)),
+            }
+        }
+
+        categories
+            .sort_by_key(|(category, ..)| CATEGORIES.iter().position(|other| other == category));
+
+        Ok(categories
+            .into_iter()
+            .map(|(category, form, _)| (category, form))
+            .collect())
+    }
+}
+
+impl Expression {
+    fn evaluate(&self, n: u64) -> u64 {
+        match self {
+            Self::Number(number) => *number,
+            Self::N => n,
+            Self::Not(expression) => u64::from(expression.evaluate(n) == 0),
+            Self::Binary(operator, left, right) => {
+                let left = left.evaluate(n);
+                let right = right.evaluate(n);
+
+                match *operator {
+                    "||" => u64::from(left != 0 || right != 0),
+                    "&&" => u64::from(left != 0 && right != 0),
+                    "==" => u64::from(left == right),
+                    "!=" => u64::from(left != right),
+                    "<" => u64::from(left < right),
+                    "<=" => u64::from(left <= right),
+                    ">" => u64::from(left > right),
+                    ">=" => u64::from(left >= right),
+                    "+" => left.wrapping_add(right),
+                    "-" => left.wrapping_sub(right),
+                    "*" => left.wrapping_mul(right),
+                    "/" => left.checked_div(right).unwrap_or_default(),
+                    _ => left.checked_rem(right).unwrap_or_default(),
+                }
+            }
+            Self::Condition(condition, then, otherwise) => {
+                if condition.evaluate(n) != 0 {
+                    then.evaluate(n)
+                } else {
+                    otherwise.evaluate(n)
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Parser<'a> {
+    const OPERATORS: [&'static [&'static str]; 6] = [
+        &["||"],
+        &["&&"],
+        &["==", "!="],
+        &["<=", ">=", "<", ">"],
+        &["+", "-"],
+        &["*", "/", "%"],
+    ];
+
+    fn parse(formula: &'a str) -> Option<Expression> {
+        let mut parser = Self {
+            tokens: tokenize(formula)?,
+            position: 0,
+        };
+        let expression = parser.condition()?;
+
+        (parser.position == parser.tokens.len()).then_some(expression)
+    }
+
+    fn condition(&mut self) -> Option<Expression> {
+        let condition = self.binary(0)?;
+        if !self.next_if("?") {
+            return Some(condition);
+        }
+
+        let then = self.condition()?;
+        self.next_if(":").then_some(())?;
+        let otherwise = self.condition()?;
+
+        Some(Expression::Condition(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    fn binary(&mut self, level: usize) -> Option<Expression> {
+        let Some(operators) = Self::OPERATORS.get(level) else {
+            return self.unary();
+        };
+
+        let mut left = self.binary(level + 1)?;
+        while let Some(operator) = operators
+            .iter()
+            .find(|operator| self.tokens.get(self.position) == Some(*operator))
+        {
+            self.position += 1;
+            let right = self.binary(level + 1)?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Some(left)
+    }
+
+    fn unary(&mut self) -> Option<Expression> {
+        if self.next_if("!") {
+            return Some(Expression::Not(Box::new(self.unary()?)));
+        }
+        if self.next_if("(") {
+            let expression = self.condition()?;
+            self.next_if(")").then_some(())?;
+
+            return Some(expression);
+        }
+
+        let token = *self.tokens.get(self.position)?;
+        self.position += 1;
+
+        if token == "n" {
+            Some(Expression::N)
+        } else {
+            token.parse().ok().map(Expression::Number)
+        }
+    }
+
+    fn next_if(&mut self, token: &str) -> bool {
+        let matches = self.tokens.get(self.position) == Some(&token);
+        if matches {
+            self.position += 1;
+        }
+
+        matches
+    }
+}
+
+fn tokenize(formula: &str) -> Option<Vec<&str>> {
+    let mut tokens = Vec::new();
+    let mut rest = formula.trim();
+    while !rest.is_empty() {
+        let length = if rest.starts_with(|char: char| char.is_ascii_digit()) {
+            rest.find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(rest.len())
+        } else if ["||", "&&", "==", "!=", "<=", ">="]
+            .iter()
+            .any(|operator| rest.starts_with(operator))
+        {
+            2
+        } else if rest.starts_with([
+            'n', '?', ':', '<', '>', '+', '-', '*', '/', '%', '!', '(', ')',
+        ]) {
+            1
+        } else {
+            None?
+        };
+
+        let (token, remaining) = rest.split_at(length);
+        tokens.push(token);
+        rest = remaining.trim_start();
+    }
+
+    Some(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluralForms;
+    use crate::utils::plural_rules::PluralRules;
+
+    fn categories(language: &str, header: &str) -> Result<Vec<(&'static str, usize)>, String> {
+        PluralForms::parse(header)
+            .unwrap()
+            .categories(&PluralRules::new(language).unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        assert!(PluralForms::parse("nplurals=2; plural=(n != 1);").is_some());
+        assert!(PluralForms::parse(" nplurals = 1 ; plural = 0 ").is_some());
+        assert!(PluralForms::parse("nplurals=2; plural=(n != 1;").is_none());
+        assert!(PluralForms::parse("nplurals=0; plural=0;").is_none());
+        assert!(PluralForms::parse("nplurals=INTEGER; plural=EXPRESSION;").is_none());
+        assert!(PluralForms::parse("plural=n;").is_none());
+    }
+
+    #[test]
+    fn categories_from_formula() {
+        assert_eq!(
+            categories("en", "nplurals=2; plural=(n != 1);"),
+            Ok(vec![("one", 0), ("other", 1)])
+        );
+        assert_eq!(
+            categories("fr", "nplurals=2; plural=(n > 1);"),
+            Ok(vec![("one", 0), ("many", 1), ("other", 1)])
+        );
+        assert_eq!(
+            categories(
+                "ru",
+                "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : \
+                n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);"
+            ),
+            Ok(vec![("one", 0), ("few", 1), ("many", 2)])
+        );
+        assert_eq!(
+            categories("ja", "nplurals=1; plural=0;"),
+            Ok(vec![("other", 0)])
+        );
+        assert_eq!(
+            categories(
+                "lv",
+                "nplurals=3; plural=(n%10==0 || n%100>=11 && n%100<=19 ? 2 : \
+                n%10==1 && n%100!=11 ? 0 : 1);"
+            ),
+            Ok(vec![("zero", 2), ("one", 0), ("other", 1)])
+        );
+    }
+
+    #[test]
+    fn ambiguous_formula() {
+        assert_eq!(
+            categories(
+                "lv",
+                "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n != 0 ? 1 : 2);"
+            ),
+            Err("`Plural-Forms` is ambiguous for the CLDR category `zero`: \
+                0 selects form 2, but 10 selects form 1"
+                .to_string())
+        );
+        assert_eq!(
+            categories("en", "nplurals=2; plural=n;"),
+            Err("`Plural-Forms` selects form 2 for 2, but `nplurals` is 2".to_string())
+        );
+    }
+}
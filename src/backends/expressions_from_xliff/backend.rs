@@ -0,0 +1,91 @@
+use crate::{
+    backends::expressions_from_xliff::parser::parse_resource,
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        catalog::{Catalog, CatalogArguments, Resource},
+        combine_errors,
+    },
+};
+use proc_macro::TokenStream;
+use syn::parse;
+
+pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
+    let arguments = &parse::<CatalogArguments>(input)?;
+    let names_provider = &NamesProvider::new(arguments.path.clone());
+
+    let default = arguments
+        .variants
+        .iter()
+        .position(|variant| *variant == arguments.default)
+        .unwrap_or_default();
+    let mut variants = (0..arguments.variants.len())
+        .filter(|variant| *variant != default)
+        .collect::<Vec<_>>();
+    if variants.is_empty() {
+        variants.push(default);
+    }
+
+    let tags = variants
+        .iter()
+        .map(|variant| arguments.tags[*variant].clone())
+        .collect::<Vec<_>>();
+    let resources = Resource::read_localized(&arguments.file, &tags)?;
+    let sources = (0..arguments.variants.len())
+        .map(|variant| {
+            let index = variants.iter().position(|other| *other == variant);
+
+            &resources[index.unwrap_or_default()]
+        })
+        .collect();
+    let mut catalog = Catalog::new(arguments, sources);
+
+    let mut errors = Vec::new();
+    for (variant, resource) in variants.into_iter().zip(&resources) {
+        let units = match parse_resource(arguments, variant, resource) {
+            Ok(units) => units,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+
+        let mut keys = Vec::new();
+        for unit in units {
+            let error = |message: String| resource.error(&arguments.file, unit.line, message);
+            let name = unit.key.join(".");
+            if keys.contains(&unit.key) {
+                errors.push(error(format!("duplicate unit `{name}`")));
+                continue;
+            }
+            keys.push(unit.key.clone());
+
+            if let Some(note) = unit.note {
+                catalog.note(unit.key.clone(), note);
+            }
+            if let Some(previous) = catalog.insert(default, unit.key.clone(), unit.source.clone())
+                && previous != unit.source
+            {
+                errors.push(error(format!(
+                    "the source of `{name}` differs between XLIFF files"
+                )));
+            }
+            if variant == default {
+                continue;
+            }
+
+            if let Some(target) = unit.target {
+                catalog.insert(variant, unit.key.clone(), target);
+            }
+            if let Some(state) = unit.state {
+                catalog.state(variant, unit.key, state);
+            }
+        }
+    }
+
+    if let Some(error) = combine_errors(errors) {
+        Err(error)?;
+    }
+
+    Ok(catalog.expand(names_provider)?.into())
+}
@@ -0,0 +1,5 @@
+mod backend;
+mod parser;
+mod xml;
+
+pub use backend::backend as expressions_from_xliff_backend;
@@ -0,0 +1,270 @@
+use crate::{
+    backends::{
+        expression::STATES,
+        expressions_from_xliff::xml::{Element, Node, parse_document},
+    },
+    utils::{
+        aliases::SynResult,
+        catalog::{CatalogArguments, Resource, Translation},
+        plural_rules::CATEGORIES,
+        template::Piece,
+    },
+};
+use syn::LitStr;
+
+pub const PLURAL_TYPE: &str = "localize_it:plural";
+
+pub struct Unit {
+    pub key: Vec<String>,
+    pub line: usize,
+    pub note: Option<String>,
+    pub source: Translation,
+    pub target: Option<Translation>,
+    pub state: Option<String>,
+}
+
+struct Content {
+    source: Vec<Piece>,
+    target: Option<Vec<Piece>>,
+    state: Option<String>,
+}
+
+pub fn parse_resource(
+    arguments: &CatalogArguments,
+    variant: usize,
+    resource: &Resource,
+) -> SynResult<Vec<Unit>> {
+    let file = &arguments.file;
+    let root = parse_document(file, resource)?;
+    let error = |line: usize, message: String| resource.error(file, line, message);
+
+    if root.name != "xliff"
+        || !root
+            .attribute("version")
+            .is_some_and(|v| v.starts_with("2."))
+    {
+        Err(error(root.line, "expected an XLIFF 2 document".to_string()))?;
+    }
+
+    let default = arguments
+        .variants
+        .iter()
+        .position(|variant| *variant == arguments.default)
+        .unwrap_or_default();
+    let check_language =
+        |attribute: &str, expected: &str, required: bool| match root.attribute(attribute) {
+            Some(language) if !language.eq_ignore_ascii_case(expected) => Err(error(
+                root.line,
+                format!("`{attribute}` is `{language}`, expected `{expected}`"),
+            )),
+            None if required => Err(error(root.line, format!("missing `{attribute}`"))),
+            _ => Ok(()),
+        };
+    check_language("srcLang", &arguments.tags[default], true)?;
+    check_language("trgLang", &arguments.tags[variant], variant != default)?;
+
+    let mut units = Vec::new();
+    for element in root.elements().filter(|element| element.name == "file") {
+        collect(resource, file, element, &mut units)?;
+    }
+
+    Ok(units)
+}
+
+fn collect(
+    resource: &Resource,
+    file: &LitStr,
+    parent: &Element,
+    units: &mut Vec<Unit>,
+) -> SynResult<()> {
+    let error = |line: usize, message: String| resource.error(file, line, message);
+
+    for element in parent.elements() {
+        let id = || {
+            element
+                .attribute("id")
+                .ok_or_else(|| error(element.line, format!("`<{}>` without `id`", element.name)))
+        };
+
+        match element.name.as_str() {
+            "group" if element.attribute("type") == Some(PLURAL_TYPE) => {
+                let id = id()?;
+                let mut source = Vec::new();
+                let mut target = Vec::new();
+                let mut states = Vec::new();
+                for unit in element.elements().filter(|unit| unit.name == "unit") {
+                    let unit_id = unit
+                        .attribute("id")
+                        .ok_or_else(|| error(unit.line, "`<unit>` without `id`".to_string()))?;
+                    let category = unit_id
+                        .strip_prefix(id)
+                        .and_then(|category| category.strip_prefix('.'))
+                        .filter(|category| CATEGORIES.contains(category))
+                        .ok_or_else(|| {
+                            error(
+                                unit.line,
+                                format!("expected `{id}.<plural category>`, found `{unit_id}`"),
+                            )
+                        })?;
+
+                    let content = content(resource, file, unit)?;
+                    source.push((category.to_string(), content.source));
+                    if let Some(pieces) = content.target {
+                        target.push((category.to_string(), pieces));
+                    }
+                    states.extend(content.state);
+                }
+
+                let plural = |categories: Vec<(String, Vec<Piece>)>| -> SynResult<Translation> {
+                    let default = categories
+                        .iter()
+                        .position(|(category, _)| category == "other")
+                        .ok_or_else(|| {
+                            error(element.line, format!("`{id}` has no `other` form"))
+                        })?;
+                    let selector = categories[default]
+                        .1
+                        .iter()
+                        .find_map(|piece| match piece {
+                            Piece::Placeholder(placeholder) => Some(placeholder.clone()),
+                            Piece::Text(_) => None,
+                        })
+                        .unwrap_or("n".to_string());
+
+                    Ok(Translation::Plural {
+                        selector,
+                        categories,
+                        default,
+                    })
+                };
+
+                units.push(Unit {
+                    key: id.split('.').map(str::to_string).collect(),
+                    line: element.line,
+                    note: notes(element),
+                    source: plural(source)?,
+                    target: (!target.is_empty()).then(|| plural(target)).transpose()?,
+                    state: lowest(states),
+                });
+            }
+            "group" => collect(resource, file, element, units)?,
+            "unit" => {
+                let id = id()?;
+                let content = content(resource, file, element)?;
+
+                units.push(Unit {
+                    key: id.split('.').map(str::to_string).collect(),
+                    line: element.line,
+                    note: notes(element),
+                    source: Translation::Text(content.source),
+                    target: content.target.map(Translation::Text),
+                    state: content.state,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn content(resource: &Resource, file: &LitStr, unit: &Element) -> SynResult<Content> {
+    let error = |line: usize, message: String| resource.error(file, line, message);
+
+    let mut source = Vec::new();
+    let mut target = None::<Vec<Piece>>;
+    let mut states = Vec::new();
+    for part in unit
+        .elements()
+        .filter(|part| matches!(part.name.as_str(), "segment" | "ignorable"))
+    {
+        if let Some(segment_state) = part.attribute("state") {
+            if !STATES.contains(&segment_state) {
+                Err(error(
+                    part.line,
+                    format!("unknown segment state `{segment_state}`"),
+                ))?;
+            }
+
+            states.push(segment_state.to_string());
+        }
+
+        let source_element = part
+            .element("source")
+            .ok_or_else(|| error(part.line, "`<segment>` without `<source>`".to_string()))?;
+        inline(resource, file, source_element, &mut source)?;
+
+        if let Some(target_element) = part.element("target") {
+            inline(
+                resource,
+                file,
+                target_element,
+                target.get_or_insert_default(),
+            )?;
+        }
+    }
+
+    Ok(Content {
+        source,
+        target: target.filter(|pieces| !pieces.is_empty()),
+        state: lowest(states),
+    })
+}
+
+fn inline(
+    resource: &Resource,
+    file: &LitStr,
+    element: &Element,
+    pieces: &mut Vec<Piece>,
+) -> SynResult<()> {
+    for child in &element.children {
+        match child {
+            Node::Text(text) => match pieces.last_mut() {
+                Some(Piece::Text(last)) => last.push_str(text),
+                _ => pieces.push(Piece::Text(text.clone())),
+            },
+            Node::Element(child) if child.name == "ph" => {
+                let placeholder = ["disp", "equiv"]
+                    .into_iter()
+                    .filter_map(|attribute| child.attribute(attribute))
+                    .find_map(|value| value.strip_prefix('{')?.strip_suffix('}'))
+                    .ok_or_else(|| {
+                        resource.error(
+                            file,
+                            child.line,
+                            "`<ph>` without a `{name}` placeholder in `disp` or `equiv`",
+                        )
+                    })?;
+
+                pieces.push(Piece::Placeholder(placeholder.to_string()));
+            }
+            Node::Element(child) if matches!(child.name.as_str(), "pc" | "mrk") => {
+                inline(resource, file, child, pieces)?
+            }
+            Node::Element(child) => Err(resource.error(
+                file,
+                child.line,
+                format!("unsupported inline element `<{}>`", child.name),
+            ))?,
+        }
+    }
+
+    Ok(())
+}
+
+fn notes(element: &Element) -> Option<String> {
+    let notes = element
+        .element("notes")?
+        .elements()
+        .filter(|note| note.name == "note")
+        .map(Element::text)
+        .collect::<Vec<_>>();
+
+    (!notes.is_empty()).then(|| notes.join("\n"))
+}
+
+fn lowest(states: Vec<String>) -> Option<String> {
+    states
+        .into_iter()
+        .min_by_key(|state| STATES.iter().position(|other| other == state))
+}
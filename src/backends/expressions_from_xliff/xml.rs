@@ -0,0 +1,324 @@
+use crate::utils::{
+    aliases::{SynError, SynResult},
+    catalog::Resource,
+};
+use syn::LitStr;
+
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+    pub line: usize,
+}
+
+struct Parser<'a> {
+    file: &'a LitStr,
+    resource: &'a Resource,
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+}
+
+pub fn parse_document(file: &LitStr, resource: &Resource) -> SynResult<Element> {
+    let mut parser = Parser {
+        file,
+        resource,
+        chars: resource
+            .content
+            .trim_start_matches('\u{feff}')
+            .chars()
+            .collect(),
+        position: 0,
+        line: 1,
+    };
+
+    parser.skip_misc()?;
+    if parser.peek() != Some('<') {
+        Err(parser.error("expected the root element"))?;
+    }
+    let root = parser.parse_element()?;
+
+    parser.skip_misc()?;
+    if parser.peek().is_some() {
+        Err(parser.error("unexpected content after the root element"))?;
+    }
+
+    Ok(root)
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Self> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    pub fn element(&self, name: &str) -> Option<&Self> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Element(element) => element.text(),
+                Node::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Parser<'_> {
+    fn parse_element(&mut self) -> SynResult<Element> {
+        let line = self.line;
+        self.position += 1;
+
+        let qualified_name = self.parse_name()?;
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+
+            if self.eat_str("/>") {
+                return Ok(Element {
+                    name: local_name(&qualified_name),
+                    attributes,
+                    children: Vec::new(),
+                    line,
+                });
+            }
+            if self.eat('>') {
+                break;
+            }
+
+            let name = self.parse_name()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            self.skip_whitespace();
+            attributes.push((name, self.parse_attribute_value()?));
+        }
+
+        let mut children = Vec::new();
+        let mut text = String::new();
+        loop {
+            if self.eat_str("</") {
+                let closing = self.parse_name()?;
+                if closing != qualified_name {
+                    Err(self.error(format!(
+                        "expected `</{qualified_name}>`, found `</{closing}>`"
+                    )))?;
+                }
+                self.skip_whitespace();
+                self.expect('>')?;
+                break;
+            }
+
+            match self.peek() {
+                Some('<') if self.eat_str("<!--") => self.skip_until("-->")?,
+                Some('<') if self.eat_str("<?") => self.skip_until("?>")?,
+                Some('<') if self.eat_str("<![CDATA[") => {
+                    let start = self.position;
+                    self.skip_until("]]>")?;
+                    text.extend(&self.chars[start..self.position - 3]);
+                }
+                Some('<') => {
+                    if !text.is_empty() {
+                        children.push(Node::Text(std::mem::take(&mut text)));
+                    }
+                    children.push(Node::Element(self.parse_element()?));
+                }
+                Some('&') => text.push(self.parse_reference()?),
+                Some(char) => {
+                    self.next();
+                    text.push(char);
+                }
+                None => Err(self.error_at(line, format!("unclosed element `{qualified_name}`")))?,
+            }
+        }
+        if !text.is_empty() {
+            children.push(Node::Text(text));
+        }
+
+        Ok(Element {
+            name: local_name(&qualified_name),
+            attributes,
+            children,
+            line,
+        })
+    }
+
+    fn parse_name(&mut self) -> SynResult<String> {
+        let mut name = String::new();
+        while let Some(char) = self.peek() {
+            if !(char.is_alphanumeric() || matches!(char, ':' | '_' | '-' | '.')) {
+                break;
+            }
+
+            self.position += 1;
+            name.push(char);
+        }
+
+        if name.is_empty() {
+            Err(self.error("expected a name"))?;
+        }
+
+        Ok(name)
+    }
+
+    fn parse_attribute_value(&mut self) -> SynResult<String> {
+        let quote = match self.next() {
+            Some(quote @ ('"' | '\'')) => quote,
+            _ => Err(self.error("expected a quoted attribute value"))?,
+        };
+
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                Some(char) if char == quote => {
+                    self.position += 1;
+                    break;
+                }
+                Some('&') => value.push(self.parse_reference()?),
+                Some('<') | None => Err(self.error("unterminated attribute value"))?,
+                Some(char) => {
+                    self.next();
+                    value.push(char);
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn parse_reference(&mut self) -> SynResult<char> {
+        self.position += 1;
+
+        let mut name = String::new();
+        loop {
+            match self.next() {
+                Some(';') => break,
+                Some(char) if name.len() < 10 => name.push(char),
+                _ => Err(self.error("invalid character reference"))?,
+            }
+        }
+
+        let code = if let Some(hex) = name.strip_prefix("#x") {
+            u32::from_str_radix(hex, 16).ok()
+        } else if let Some(decimal) = name.strip_prefix('#') {
+            decimal.parse().ok()
+        } else {
+            None
+        };
+
+        match name.as_str() {
+            "lt" => Ok('<'),
+            "gt" => Ok('>'),
+            "amp" => Ok('&'),
+            "quot" => Ok('"'),
+            "apos" => Ok('\''),
+            _ => code
+                .and_then(char::from_u32)
+                .ok_or_else(|| self.error(format!("unknown reference `&{name};`"))),
+        }
+    }
+
+    fn skip_misc(&mut self) -> SynResult<()> {
+        loop {
+            self.skip_whitespace();
+
+            if self.eat_str("<?") {
+                self.skip_until("?>")?;
+            } else if self.eat_str("<!--") {
+                self.skip_until("-->")?;
+            } else if self.eat_str("<!") {
+                self.skip_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_until(&mut self, end: &str) -> SynResult<()> {
+        let line = self.line;
+        while !self.eat_str(end) {
+            if self.next().is_none() {
+                Err(self.error_at(line, format!("expected `{end}`")))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> SynResult<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{expected}`")))
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.position += 1;
+        }
+
+        matched
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let matched = expected
+            .chars()
+            .enumerate()
+            .all(|(i, char)| self.chars.get(self.position + i) == Some(&char));
+        if matched {
+            self.position += expected.chars().count();
+        }
+
+        matched
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek();
+        self.position += 1;
+        if char == Some('\n') {
+            self.line += 1;
+        }
+
+        char
+    }
+
+    fn error(&self, message: impl Into<String>) -> SynError {
+        self.error_at(self.line, message)
+    }
+
+    fn error_at(&self, line: usize, message: impl Into<String>) -> SynError {
+        self.resource.error(self.file, line, message.into())
+    }
+}
+
+fn local_name(name: &str) -> String {
+    name.rsplit(':').next().unwrap_or(name).to_string()
+}
@@ -1,19 +1,43 @@
 use crate::utils::{
     aliases::SynResult,
-    errors::{LocaleVariantPositionError, NoLocaleVariantError, UnknownArgumentError},
+    errors::{
+        DuplicateLocaleVariantError, DuplicateStorageError, DuplicateTagError, FallbackCycleError,
+        InvalidTagError, LocaleVariantPositionError, NoLocaleVariantError, UnknownArgumentError,
+        UnknownLocaleVariantError, UnknownStorageError,
+    },
+    language_tag::LanguageTag,
     names::DEFAULT_ENUM_LOCALE,
     typed_parse::TypedParse,
 };
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::{HashMap, HashSet};
 use syn::{
-    LitStr, Path, Token,
+    LitBool, LitInt, LitStr, Path, Token, parenthesized,
     parse::{Parse, ParseStream},
+    token::{Bracket, Paren},
 };
 
+pub enum Storage {
+    Disabled,
+    Global,
+    ThreadLocal,
+    AtomicU8,
+    UnsafeSingleCore,
+    Custom(Path),
+    Named(Vec<Ident>),
+}
+
 pub struct Arguments {
     pub variants: Vec<Ident>,
     pub variants_label: Vec<LitStr>,
-    pub storage: bool,
+    pub variants_tag: Vec<String>,
+    pub variants_discriminant: Vec<Option<LitInt>>,
+    pub variants_fallback: Option<Vec<Ident>>,
+    pub storage: Storage,
+    pub env: bool,
+    pub persist: bool,
+    pub test_support: bool,
     pub path: Option<Path>,
     pub default: Ident,
     pub locale_name: String,
@@ -24,23 +48,36 @@ impl Parse for Arguments {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let mut variants = Vec::new();
         let mut variants_label = Vec::new();
-        let mut storage = false;
+        let mut variants_tag = Vec::new();
+        let mut explicit_tags = Vec::new();
+        let mut variants_discriminant = Vec::new();
+        let mut storage = Storage::Disabled;
+        let mut env = false;
+        let mut persist = false;
+        let mut test_support = false;
         let mut path = None;
         let mut default = None;
         let mut locale_name = None;
         let mut derive = Vec::new();
+        let mut fallback = None;
 
         let mut variants_is_end = false;
         input.parse_arguments(|argument, processor| {
-            if input.peek(Token![=]) && !input.peek(Token![=>]) {
+            if input.peek(Token![=]) && !input.peek(Token![=>]) && !input.peek2(LitInt) {
                 input.parse::<Token![=]>()?;
 
                 match processor.process(&argument)?.as_str() {
-                    "storage" => storage = input.parse_bool("storage")?,
+                    "storage" => storage = parse_storage(input)?,
+                    "env" => env = input.parse_bool("env")?,
+                    "persist" => persist = input.parse_bool("persist")?,
+                    "test_support" => test_support = input.parse_bool("test_support")?,
                     "path" => path = Some(input.parse_path("path")?),
                     "default" => default = Some(input.parse_ident("default")?),
                     "locale_name" => locale_name = Some(input.parse_ident("locale_name")?),
                     "derive" => derive = input.parse_array("derive", Path::parse)?,
+                    "fallback" => {
+                        fallback = Some(input.parse_map("fallback", Ident::parse, Ident::parse)?)
+                    }
                     _ => Err(UnknownArgumentError::new(argument))?,
                 }
 
@@ -48,6 +85,20 @@ impl Parse for Arguments {
             } else if variants_is_end {
                 Err(LocaleVariantPositionError::new(argument))?;
             } else {
+                let tag = if input.peek(Paren) {
+                    parse_variant_tag(input)?
+                } else {
+                    None
+                };
+
+                let discriminant = if input.peek(Token![=]) && !input.peek(Token![=>]) {
+                    input.parse::<Token![=]>()?;
+
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+
                 let label = if input.peek(Token![=>]) {
                     input.parse::<Token![=>]>()?;
 
@@ -56,6 +107,15 @@ impl Parse for Arguments {
                     LitStr::new(&argument.to_string(), argument.span())
                 };
 
+                explicit_tags.push(tag.is_some());
+                let tag = tag.unwrap_or_else(|| {
+                    let variant = argument.to_string();
+
+                    LanguageTag::from_variant(&variant).unwrap_or(variant.to_lowercase())
+                });
+
+                variants_tag.push(tag);
+                variants_discriminant.push(discriminant);
                 variants.push(argument);
                 variants_label.push(label);
             }
@@ -63,8 +123,23 @@ impl Parse for Arguments {
             Ok(())
         })?;
 
+        let mut exist_tags = HashMap::new();
+        for ((variant, tag), explicit) in variants.iter().zip(&variants_tag).zip(explicit_tags) {
+            let previous = exist_tags.insert(LanguageTag::matching_subtags(tag), explicit);
+            if previous.is_some_and(|previous| previous || explicit) {
+                Err(DuplicateTagError::new(variant.span(), tag))?;
+            }
+        }
+
         let default =
             default.unwrap_or(variants.first().ok_or(NoLocaleVariantError::new())?.clone());
+        if !variants.contains(&default) {
+            Err(UnknownLocaleVariantError::new(&default, &variants))?;
+        }
+
+        let variants_fallback = fallback
+            .map(|fallback| resolve_fallback(&variants, &default, fallback))
+            .transpose()?;
         let locale_name = locale_name.map_or(DEFAULT_ENUM_LOCALE.to_string(), |locale_name| {
             locale_name.to_string()
         });
@@ -72,7 +147,13 @@ impl Parse for Arguments {
         Ok(Self {
             variants,
             variants_label,
+            variants_tag,
+            variants_discriminant,
+            variants_fallback,
             storage,
+            env,
+            persist,
+            test_support,
             path,
             default,
             locale_name,
@@ -80,3 +161,213 @@ impl Parse for Arguments {
         })
     }
 }
+
+impl Storage {
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+}
+
+impl Arguments {
+    pub fn locale_arguments(&self) -> TokenStream {
+        let variants = &self.variants;
+        let variants_tag = &self.variants_tag;
+        let fallbacks = self.variants_fallback.as_ref().map(|fallbacks| {
+            quote! {
+                fallbacks = [#(#fallbacks),*],
+            }
+        });
+
+        quote! {
+            variants = [#(#variants),*],
+            tags = [#(#variants_tag),*],
+            #fallbacks
+        }
+    }
+}
+
+fn parse_storage(input: ParseStream) -> SynResult<Storage> {
+    if input.peek(LitBool) {
+        return Ok(if input.parse_bool("storage")? {
+            Storage::Global
+        } else {
+            Storage::Disabled
+        });
+    }
+
+    if input.peek(Bracket) {
+        let slots = input.parse_array("storage", Ident::parse)?;
+
+        let mut exist_slots = HashSet::new();
+        for slot in &slots {
+            if !exist_slots.insert(slot) {
+                Err(DuplicateStorageError::new(slot))?;
+            }
+        }
+
+        return Ok(if slots.is_empty() {
+            Storage::Disabled
+        } else {
+            Storage::Named(slots)
+        });
+    }
+
+    let storage = input.parse_path("storage")?;
+    let Some(mode) = storage.get_ident() else {
+        return Ok(Storage::Custom(storage));
+    };
+
+    Ok(match mode.to_string().as_str() {
+        "thread_local" => Storage::ThreadLocal,
+        "atomic_u8" => Storage::AtomicU8,
+        "unsafe_single_core" => Storage::UnsafeSingleCore,
+        name if name.starts_with(char::is_lowercase) => Err(UnknownStorageError::new(
+            mode,
+            &[
+                "true",
+                "false",
+                "thread_local",
+                "atomic_u8",
+                "unsafe_single_core",
+            ],
+        ))?,
+        _ => Storage::Custom(storage),
+    })
+}
+
+fn parse_variant_tag(input: ParseStream) -> SynResult<Option<String>> {
+    let content;
+    parenthesized!(content in input);
+
+    let mut tag = None;
+    content.parse_arguments(|argument, processor| {
+        content.parse::<Token![=]>()?;
+
+        match processor.process(&argument)?.as_str() {
+            "tag" => {
+                let literal = content.parse_lit_str("tag")?;
+                let language_tag =
+                    LanguageTag::parse(&literal.value()).ok_or(InvalidTagError::new(&literal))?;
+
+                tag = Some(language_tag.to_string());
+            }
+            _ => Err(UnknownArgumentError::new(argument))?,
+        }
+
+        Ok(())
+    })?;
+
+    Ok(tag)
+}
+
+fn resolve_fallback(
+    variants: &[Ident],
+    default: &Ident,
+    fallback: Vec<(Ident, Ident)>,
+) -> SynResult<Vec<Ident>> {
+    let find_variant = |variant: &Ident| {
+        variants
+            .iter()
+            .position(|exist_variant| exist_variant == variant)
+            .ok_or(UnknownLocaleVariantError::new(variant, variants))
+    };
+
+    let default_index = find_variant(default)?;
+    let mut next = vec![default_index; variants.len()];
+    let mut sources = variants.to_vec();
+    let mut exist_keys = HashSet::new();
+    for (from, to) in fallback {
+        let from_index = find_variant(&from)?;
+        let to_index = find_variant(&to)?;
+
+        if !exist_keys.insert(from_index) {
+            Err(DuplicateLocaleVariantError::new(&from))?;
+        }
+
+        next[from_index] = to_index;
+        sources[from_index] = from;
+    }
+
+    for (i, source) in sources.iter().enumerate() {
+        let mut current = i;
+        for _ in 0..variants.len() {
+            current = next[current];
+        }
+
+        if current != default_index || next[default_index] != default_index {
+            Err(FallbackCycleError::new(source))?;
+        }
+    }
+
+    Ok(next.into_iter().map(|i| variants[i].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arguments;
+
+    fn error(input: &str) -> String {
+        syn::parse_str::<Arguments>(input)
+            .err()
+            .map(|error| error.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn default_variant() {
+        assert_eq!(error("En, Ru, default = Ru"), "");
+        assert_eq!(
+            error("En, Ru, default = Rus"),
+            "Unknown locale variant `Rus`, did you mean `Ru`?"
+        );
+        assert_eq!(error("En, Ru, default = Fr"), "Unknown locale variant `Fr`");
+    }
+
+    #[test]
+    fn fallback_variants() {
+        assert_eq!(
+            error("En, EnGb, fallback = { EnBg => En }"),
+            "Unknown locale variant `EnBg`, did you mean `EnGb`?"
+        );
+        assert_eq!(
+            error("En, EnGb, fallback = { EnGb => En, EnGb => En }"),
+            "Locale variant `EnGb` is duplicated"
+        );
+    }
+
+    #[test]
+    fn variant_tags() {
+        assert_eq!(error("English, Ukrainian, Portuguese, X"), "");
+        assert_eq!(error("En, English, Eng"), "");
+        assert_eq!(
+            error("En, English(tag = \"en\")"),
+            "Duplicate language tag `en`"
+        );
+        assert_eq!(error("En(tag = \"en\"), Eng"), "");
+        assert_eq!(
+            error("En(tag = \"en\"), EnUs(tag = \"en\")"),
+            "Duplicate language tag `en`"
+        );
+        assert_eq!(
+            error("En(tag = \"e1\")"),
+            "`e1` is not a valid BCP 47 language tag"
+        );
+    }
+
+    #[test]
+    fn storage_modes() {
+        assert_eq!(error("En, storage = thread_local"), "");
+        assert_eq!(error("En, storage = Backend"), "");
+        assert_eq!(error("En, storage = self::backend"), "");
+        assert_eq!(
+            error("En, storage = thread_locl"),
+            "Unknown storage `thread_locl`, did you mean `thread_local`? \
+             A custom backend is a type, such as `Backend` or `self::backend`"
+        );
+        assert_eq!(
+            error("En, storage = global"),
+            "Unknown storage `global`. \
+             A custom backend is a type, such as `Backend` or `self::backend`"
+        );
+    }
+}
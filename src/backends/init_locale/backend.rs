@@ -2,11 +2,20 @@ use crate::{
     backends::init_locale::{
         arguments::Arguments,
         parts::{
-            enum_locale, macro_expression, macro_expression_part, macro_expressions,
-            macro_expressions_from_files, macro_expressions_part, macro_localize, mod_storage,
+            enum_locale, macro_export_csv, macro_export_json, macro_export_pot, macro_export_xliff,
+            macro_expression, macro_expression_part, macro_expressions,
+            macro_expressions_from_files, macro_expressions_from_resource, macro_expressions_part,
+            macro_localize, mod_context, mod_plural, mod_storage,
+        },
+    },
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        names::{
+            MACRO_EXPRESSIONS_FROM_CSV, MACRO_EXPRESSIONS_FROM_FTL, MACRO_EXPRESSIONS_FROM_JSON,
+            MACRO_EXPRESSIONS_FROM_PO, MACRO_EXPRESSIONS_FROM_XLIFF,
         },
     },
-    utils::{NamesProvider, aliases::SynResult},
 };
 use proc_macro::TokenStream;
 use quote::quote;
@@ -16,24 +25,76 @@ pub fn backend(input: TokenStream) -> SynResult<TokenStream> {
     let arguments = &parse::<Arguments>(input)?;
     let names_provider = &NamesProvider::new(arguments.path.clone());
 
-    let locale = enum_locale(arguments)?;
+    let locale = enum_locale(arguments, names_provider)?;
     let storage = mod_storage(arguments);
+    let context = mod_context(arguments);
+    let plural = mod_plural(arguments);
     let expression = macro_expression(arguments, names_provider)?;
     let expressions = macro_expressions(names_provider);
     let localize = macro_localize(arguments, names_provider);
     let expression_part = macro_expression_part(names_provider);
     let expressions_part = macro_expressions_part(names_provider);
     let expressions_from_files = macro_expressions_from_files(arguments, names_provider)?;
+    let expressions_from_ftl = macro_expressions_from_resource(
+        arguments,
+        names_provider,
+        cfg!(feature = "from_ftl"),
+        MACRO_EXPRESSIONS_FROM_FTL,
+        "__expressions_from_ftl",
+    )?;
+    let expressions_from_po = macro_expressions_from_resource(
+        arguments,
+        names_provider,
+        cfg!(feature = "from_po"),
+        MACRO_EXPRESSIONS_FROM_PO,
+        "__expressions_from_po",
+    )?;
+    let export_pot = macro_export_pot(arguments, names_provider)?;
+    let expressions_from_json = macro_expressions_from_resource(
+        arguments,
+        names_provider,
+        cfg!(feature = "from_json"),
+        MACRO_EXPRESSIONS_FROM_JSON,
+        "__expressions_from_json",
+    )?;
+    let export_json = macro_export_json(arguments, names_provider)?;
+    let expressions_from_csv = macro_expressions_from_resource(
+        arguments,
+        names_provider,
+        cfg!(feature = "from_csv"),
+        MACRO_EXPRESSIONS_FROM_CSV,
+        "__expressions_from_csv",
+    )?;
+    let export_csv = macro_export_csv(arguments, names_provider)?;
+    let expressions_from_xliff = macro_expressions_from_resource(
+        arguments,
+        names_provider,
+        cfg!(feature = "from_xliff"),
+        MACRO_EXPRESSIONS_FROM_XLIFF,
+        "__expressions_from_xliff",
+    )?;
+    let export_xliff = macro_export_xliff(arguments, names_provider)?;
 
     Ok(quote! {
         #locale
         #storage
+        #context
+        #plural
         #expression
         #expressions
         #localize
         #expression_part
         #expressions_part
         #expressions_from_files
+        #expressions_from_ftl
+        #expressions_from_po
+        #export_pot
+        #expressions_from_json
+        #export_json
+        #expressions_from_csv
+        #export_csv
+        #expressions_from_xliff
+        #export_xliff
     }
     .into())
 }
@@ -4,7 +4,14 @@ use quote::quote;
 
 pub fn r#enum(arguments: &Arguments) -> TokenStream {
     let locale_ident = NamesProvider::get_name(&arguments.locale_name);
-    let variants = &arguments.variants;
+    let variants = arguments
+        .variants
+        .iter()
+        .zip(&arguments.variants_discriminant)
+        .map(|(variant, discriminant)| match discriminant {
+            Some(discriminant) => quote!(#variant = #discriminant),
+            None => quote!(#variant),
+        });
     let derive = &arguments.derive;
 
     quote! {
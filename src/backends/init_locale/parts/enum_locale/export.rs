@@ -0,0 +1,604 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{
+        NamesProvider,
+        names::{MACRO_REGISTER, MOD_PRIVATE},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn export(arguments: &Arguments, names_provider: &NamesProvider) -> TokenStream {
+    if !cfg!(any(
+        feature = "from_po",
+        feature = "from_json",
+        feature = "from_csv",
+        feature = "from_xliff"
+    )) {
+        return TokenStream::new();
+    }
+
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+    let registry = registry(names_provider);
+    let pot = cfg!(feature = "from_po").then(|| pot(arguments));
+    let json = cfg!(feature = "from_json").then(|| json(arguments));
+    let csv = cfg!(feature = "from_csv").then(|| csv(arguments));
+    let xliff = cfg!(feature = "from_xliff").then(|| xliff(arguments));
+
+    quote! {
+        pub struct Message {
+            pub path: &'static str,
+            pub note: &'static str,
+            pub states: [&'static str; #locale_ident::COUNT],
+            pub value: MessageValue,
+        }
+
+        pub enum MessageValue {
+            Text([&'static str; #locale_ident::COUNT]),
+            Plural([&'static [(&'static str, &'static str)]; #locale_ident::COUNT]),
+        }
+
+        impl Message {
+            fn key(&self) -> &'static str {
+                let root = core::module_path!()
+                    .rsplit_once("::")
+                    .map_or("", |(root, _)| root);
+
+                self.path
+                    .strip_prefix(root)
+                    .and_then(|path| path.strip_prefix("::"))
+                    .or_else(|| self.path.split_once("::").map(|(_, path)| path))
+                    .unwrap_or(self.path)
+            }
+        }
+
+        fn sorted<'a>(messages: &'a [&'a Message]) -> impl Iterator<Item = &'a Message> {
+            let order = |message: &Message| message.key().split("::");
+            let mut previous = core::option::Option::<&Message>::None;
+
+            core::iter::from_fn(move || {
+                let next = messages
+                    .iter()
+                    .copied()
+                    .filter(|message| {
+                        previous.is_none_or(|previous| order(message).gt(order(previous)))
+                    })
+                    .min_by(|a, b| order(a).cmp(order(b)))?;
+                previous = core::option::Option::Some(next);
+
+                previous
+            })
+        }
+
+        fn write_key(writer: &mut impl core::fmt::Write, key: &str) -> core::fmt::Result {
+            for (i, segment) in key.split("::").enumerate() {
+                if i > 0 {
+                    writer.write_char('.')?;
+                }
+                writer.write_str(segment)?;
+            }
+
+            core::result::Result::Ok(())
+        }
+
+        #registry
+
+        #pot
+        #json
+        #csv
+        #xliff
+    }
+}
+
+// Every `expression!` registers its message in a linker section of this `init_locale!`,
+// which `messages` reads back as a slice, as the `linkme` crate does.
+fn registry(names_provider: &NamesProvider) -> TokenStream {
+    let register_ident = NamesProvider::get_name(MACRO_REGISTER);
+    let register_hashed_ident = names_provider.get_hashed_name(MACRO_REGISTER);
+    let private_mod_path = names_provider.get_component_path(MOD_PRIVATE);
+    let hash = names_provider.get_hash();
+
+    let section = format!("localize_it_messages_{hash}");
+    let elf_start = format!("__start_{section}");
+    let elf_stop = format!("__stop_{section}");
+    let apple_name = format!("__l{:013x}", hash >> 12);
+    let apple_section = format!("__DATA,{apple_name},regular,no_dead_strip");
+    let apple_start = format!("\x01section$start$__DATA${apple_name}");
+    let apple_stop = format!("\x01section$end$__DATA${apple_name}");
+    let windows_start = format!(".{section}$a");
+    let windows_section = format!(".{section}$b");
+    let windows_stop = format!(".{section}$c");
+
+    let elf = quote! {
+        any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "fuchsia",
+        )
+    };
+    let apple = quote!(target_vendor = "apple");
+    let supported = quote!(any(#elf, #apple, windows));
+
+    quote! {
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! #register_hashed_ident {
+            ($message: path) => {
+                #[cfg(#supported)]
+                const _: () = {
+                    #[used]
+                    #[cfg_attr(#elf, unsafe(link_section = #section))]
+                    #[cfg_attr(#apple, unsafe(link_section = #apple_section))]
+                    #[cfg_attr(windows, unsafe(link_section = #windows_section))]
+                    static MESSAGE: &#private_mod_path::Message = &$message;
+                };
+            };
+        }
+
+        pub use #register_hashed_ident as #register_ident;
+
+        #[cfg(#supported)]
+        pub fn messages() -> &'static [&'static Message] {
+            #[cfg(any(#elf, #apple))]
+            unsafe extern "Rust" {
+                #[cfg_attr(#elf, link_name = #elf_start)]
+                #[cfg_attr(#apple, link_name = #apple_start)]
+                static START: [&'static Message; 0];
+
+                #[cfg_attr(#elf, link_name = #elf_stop)]
+                #[cfg_attr(#apple, link_name = #apple_stop)]
+                static STOP: [&'static Message; 0];
+            }
+
+            // Keeps the section and its start and stop symbols when nothing is registered.
+            #[cfg(any(#elf, #apple))]
+            #[used]
+            #[cfg_attr(#elf, unsafe(link_section = #section))]
+            #[cfg_attr(#apple, unsafe(link_section = #apple_section))]
+            static EMPTY: [&'static Message; 0] = [];
+
+            #[cfg(windows)]
+            #[unsafe(link_section = #windows_start)]
+            static START: [&'static Message; 0] = [];
+
+            #[cfg(windows)]
+            #[unsafe(link_section = #windows_stop)]
+            static STOP: [&'static Message; 0] = [];
+
+            let start = &raw const START as *const &'static Message;
+            let stop = &raw const STOP as *const &'static Message;
+            let len = (stop as usize - start as usize) / core::mem::size_of::<&Message>();
+
+            // SAFETY: the linker places every registered `&'static Message` of the section
+            // between the start and the stop symbols.
+            unsafe { core::slice::from_raw_parts(start, len) }
+        }
+    }
+}
+
+fn pot(arguments: &Arguments) -> TokenStream {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    quote! {
+        pub fn write_pot(
+            writer: &mut impl core::fmt::Write,
+            messages: &[&Message],
+        ) -> core::fmt::Result {
+            fn write_string(
+                writer: &mut impl core::fmt::Write,
+                keyword: &str,
+                value: &str,
+            ) -> core::fmt::Result {
+                writer.write_str(keyword)?;
+                writer.write_str(" \"")?;
+                for char in value.chars() {
+                    match char {
+                        '"' => writer.write_str("\\\"")?,
+                        '\\' => writer.write_str("\\\\")?,
+                        '\n' => writer.write_str("\\n")?,
+                        '\t' => writer.write_str("\\t")?,
+                        char => writer.write_char(char)?,
+                    }
+                }
+                writer.write_str("\"\n")
+            }
+
+            writer.write_str("msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n")?;
+
+            let default = #locale_ident::DEFAULT.to_index();
+            for message in sorted(messages) {
+                writer.write_str("\n")?;
+                for line in message.note.lines() {
+                    writer.write_str("#. ")?;
+                    writer.write_str(line)?;
+                    writer.write_str("\n")?;
+                }
+                writer.write_str("msgctxt \"")?;
+                write_key(writer, message.key())?;
+                writer.write_str("\"\n")?;
+
+                match &message.value {
+                    MessageValue::Text(texts) => {
+                        write_string(writer, "msgid", texts[default])?;
+                        writer.write_str("msgstr \"\"\n")?;
+                    }
+                    MessageValue::Plural(categories) => {
+                        let find = |name: &str| {
+                            categories[default]
+                                .iter()
+                                .find(|(category, _)| *category == name)
+                                .map(|(_, template)| *template)
+                        };
+                        let other = find("other").unwrap_or_default();
+
+                        write_string(writer, "msgid", find("one").unwrap_or(other))?;
+                        write_string(writer, "msgid_plural", other)?;
+                        writer.write_str("msgstr[0] \"\"\nmsgstr[1] \"\"\n")?;
+                    }
+                }
+            }
+
+            core::result::Result::Ok(())
+        }
+    }
+}
+
+fn json(arguments: &Arguments) -> TokenStream {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    quote! {
+        pub fn write_json(
+            writer: &mut impl core::fmt::Write,
+            locale: #locale_ident,
+            messages: &[&Message],
+        ) -> core::fmt::Result {
+            fn write_string(writer: &mut impl core::fmt::Write, value: &str) -> core::fmt::Result {
+                writer.write_char('"')?;
+                for char in value.chars() {
+                    match char {
+                        '"' => writer.write_str("\\\"")?,
+                        '\\' => writer.write_str("\\\\")?,
+                        '\n' => writer.write_str("\\n")?,
+                        '\r' => writer.write_str("\\r")?,
+                        '\t' => writer.write_str("\\t")?,
+                        char if char < ' ' => core::write!(writer, "\\u{:04x}", char as u32)?,
+                        char => writer.write_char(char)?,
+                    }
+                }
+                writer.write_char('"')
+            }
+
+            fn write_indent(writer: &mut impl core::fmt::Write, depth: usize) -> core::fmt::Result {
+                writer.write_char('\n')?;
+                for _ in 0..depth {
+                    writer.write_str("  ")?;
+                }
+
+                core::result::Result::Ok(())
+            }
+
+            fn parents(key: &str) -> impl Iterator<Item = &str> {
+                key.rsplit_once("::")
+                    .map_or("", |(parents, _)| parents)
+                    .split("::")
+                    .filter(|segment| !segment.is_empty())
+            }
+
+            let locale = locale.to_index();
+            let mut previous = core::option::Option::<&str>::None;
+            let mut depth = 0;
+
+            writer.write_char('{')?;
+            for message in sorted(messages) {
+                let key = message.key();
+                let common = previous.map_or(0, |previous| {
+                    parents(previous)
+                        .zip(parents(key))
+                        .take_while(|(previous, segment)| previous == segment)
+                        .count()
+                });
+
+                for level in (common..depth).rev() {
+                    write_indent(writer, level + 1)?;
+                    writer.write_char('}')?;
+                }
+                if previous.is_some() {
+                    writer.write_char(',')?;
+                }
+
+                depth = common;
+                for segment in parents(key).skip(common) {
+                    write_indent(writer, depth + 1)?;
+                    write_string(writer, segment)?;
+                    writer.write_str(": {")?;
+                    depth += 1;
+                }
+
+                write_indent(writer, depth + 1)?;
+                write_string(writer, key.rsplit("::").next().unwrap_or(key))?;
+                writer.write_str(": ")?;
+
+                match &message.value {
+                    MessageValue::Text(texts) => write_string(writer, texts[locale])?,
+                    MessageValue::Plural(categories) => {
+                        writer.write_char('{')?;
+                        for (i, (category, template)) in categories[locale].iter().enumerate() {
+                            if i > 0 {
+                                writer.write_char(',')?;
+                            }
+
+                            write_indent(writer, depth + 2)?;
+                            write_string(writer, category)?;
+                            writer.write_str(": ")?;
+                            write_string(writer, template)?;
+                        }
+                        write_indent(writer, depth + 1)?;
+                        writer.write_char('}')?;
+                    }
+                }
+
+                previous = core::option::Option::Some(key);
+            }
+
+            for level in (0..depth).rev() {
+                write_indent(writer, level + 1)?;
+                writer.write_char('}')?;
+            }
+            if previous.is_some() {
+                write_indent(writer, 0)?;
+            }
+            writer.write_str("}\n")
+        }
+    }
+}
+
+fn csv(arguments: &Arguments) -> TokenStream {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    quote! {
+        pub fn write_csv(
+            writer: &mut impl core::fmt::Write,
+            messages: &[&Message],
+        ) -> core::fmt::Result {
+            fn write_field(writer: &mut impl core::fmt::Write, value: &str) -> core::fmt::Result {
+                if !value.contains([',', '"', '\n', '\r']) {
+                    return writer.write_str(value);
+                }
+
+                writer.write_char('"')?;
+                for char in value.chars() {
+                    if char == '"' {
+                        writer.write_char('"')?;
+                    }
+                    writer.write_char(char)?;
+                }
+                writer.write_char('"')
+            }
+
+            writer.write_str("key,notes")?;
+            for tag in #locale_ident::TAGS {
+                writer.write_char(',')?;
+                write_field(writer, tag)?;
+            }
+            writer.write_char('\n')?;
+
+            for message in sorted(messages) {
+                match &message.value {
+                    MessageValue::Text(texts) => {
+                        write_key(writer, message.key())?;
+                        writer.write_char(',')?;
+                        write_field(writer, message.note)?;
+                        for text in texts {
+                            writer.write_char(',')?;
+                            write_field(writer, text)?;
+                        }
+                        writer.write_char('\n')?;
+                    }
+                    MessageValue::Plural(variants) => {
+                        let mut note = message.note;
+                        for category in ["zero", "one", "two", "few", "many", "other"] {
+                            let find = |categories: &&[(&str, &'static str)]| {
+                                categories
+                                    .iter()
+                                    .find(|(other, _)| *other == category)
+                                    .map(|(_, template)| *template)
+                            };
+                            if variants.iter().all(|categories| find(categories).is_none()) {
+                                continue;
+                            }
+
+                            write_key(writer, message.key())?;
+                            core::write!(writer, "[{}],", category)?;
+                            write_field(writer, note)?;
+                            note = "";
+
+                            for categories in variants {
+                                writer.write_char(',')?;
+                                write_field(writer, find(categories).unwrap_or_default())?;
+                            }
+                            writer.write_char('\n')?;
+                        }
+                    }
+                }
+            }
+
+            core::result::Result::Ok(())
+        }
+    }
+}
+
+fn xliff(arguments: &Arguments) -> TokenStream {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    quote! {
+        pub fn write_xliff(
+            writer: &mut impl core::fmt::Write,
+            locale: #locale_ident,
+            messages: &[&Message],
+        ) -> core::fmt::Result {
+            fn write_escaped(writer: &mut impl core::fmt::Write, value: &str) -> core::fmt::Result {
+                for char in value.chars() {
+                    match char {
+                        '&' => writer.write_str("&amp;")?,
+                        '<' => writer.write_str("&lt;")?,
+                        '>' => writer.write_str("&gt;")?,
+                        '"' => writer.write_str("&quot;")?,
+                        char => writer.write_char(char)?,
+                    }
+                }
+
+                core::result::Result::Ok(())
+            }
+
+            fn write_template(
+                writer: &mut impl core::fmt::Write,
+                template: &str,
+            ) -> core::fmt::Result {
+                let mut rest = template;
+                while let core::option::Option::Some(char) = rest.chars().next() {
+                    rest = &rest[char.len_utf8()..];
+
+                    match char {
+                        '{' | '}' if rest.starts_with(char) => {
+                            rest = &rest[1..];
+                            writer.write_char(char)?;
+                        }
+                        '{' => {
+                            let end = rest.find('}').unwrap_or(rest.len());
+                            let name = &rest[..end];
+                            rest = &rest[(end + 1).min(rest.len())..];
+
+                            writer.write_str("<ph id=\"")?;
+                            write_escaped(writer, name)?;
+                            writer.write_str("\" disp=\"{")?;
+                            write_escaped(writer, name)?;
+                            writer.write_str("}\"/>")?;
+                        }
+                        char => {
+                            let mut buffer = [0; 4];
+                            write_escaped(writer, char.encode_utf8(&mut buffer))?;
+                        }
+                    }
+                }
+
+                core::result::Result::Ok(())
+            }
+
+            fn write_notes(
+                writer: &mut impl core::fmt::Write,
+                note: &str,
+                indent: &str,
+            ) -> core::fmt::Result {
+                if note.is_empty() {
+                    return core::result::Result::Ok(());
+                }
+
+                core::write!(writer, "{indent}  <notes>\n{indent}    <note>")?;
+                write_escaped(writer, note)?;
+                core::write!(writer, "</note>\n{indent}  </notes>\n")
+            }
+
+            fn write_unit(
+                writer: &mut impl core::fmt::Write,
+                id: (&str, &str),
+                note: &str,
+                state: &str,
+                source: &str,
+                target: core::option::Option<&str>,
+                indent: &str,
+            ) -> core::fmt::Result {
+                core::write!(writer, "{indent}<unit id=\"")?;
+                write_key(writer, id.0)?;
+                if !id.1.is_empty() {
+                    core::write!(writer, ".{}", id.1)?;
+                }
+                writer.write_str("\">\n")?;
+                write_notes(writer, note, indent)?;
+
+                core::write!(writer, "{indent}  <segment")?;
+                if !state.is_empty() {
+                    core::write!(writer, " state=\"{state}\"")?;
+                }
+                core::write!(writer, ">\n{indent}    <source>")?;
+                write_template(writer, source)?;
+                writer.write_str("</source>\n")?;
+                if let core::option::Option::Some(target) = target {
+                    core::write!(writer, "{indent}    <target>")?;
+                    write_template(writer, target)?;
+                    writer.write_str("</target>\n")?;
+                }
+                core::write!(writer, "{indent}  </segment>\n{indent}</unit>\n")
+            }
+
+            let default = #locale_ident::DEFAULT.to_index();
+            let locale = locale.to_index();
+
+            writer.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+            core::write!(
+                writer,
+                "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" \
+                srcLang=\"{}\" trgLang=\"{}\">\n  <file id=\"messages\">\n",
+                #locale_ident::TAGS[default],
+                #locale_ident::TAGS[locale],
+            )?;
+
+            for message in sorted(messages) {
+                let key = message.key();
+                let state = message.states[locale];
+
+                match &message.value {
+                    MessageValue::Text(texts) => write_unit(
+                        writer,
+                        (key, ""),
+                        message.note,
+                        state,
+                        texts[default],
+                        core::option::Option::Some(texts[locale]),
+                        "    ",
+                    )?,
+                    MessageValue::Plural(variants) => {
+                        let find = |categories: &[(&str, &'static str)], category: &str| {
+                            categories
+                                .iter()
+                                .find(|(other, _)| *other == category)
+                                .map(|(_, template)| *template)
+                        };
+
+                        writer.write_str("    <group id=\"")?;
+                        write_key(writer, key)?;
+                        writer.write_str("\" type=\"localize_it:plural\">\n")?;
+                        write_notes(writer, message.note, "    ")?;
+
+                        for category in ["zero", "one", "two", "few", "many", "other"] {
+                            let source = find(variants[default], category);
+                            let target = find(variants[locale], category);
+                            if source.is_none() && target.is_none() {
+                                continue;
+                            }
+
+                            let source = source
+                                .or_else(|| find(variants[default], "other"))
+                                .unwrap_or_default();
+                            write_unit(
+                                writer,
+                                (key, category),
+                                "",
+                                state,
+                                source,
+                                target,
+                                "      ",
+                            )?;
+                        }
+
+                        writer.write_str("    </group>\n")?;
+                    }
+                }
+            }
+
+            writer.write_str("  </file>\n</xliff>\n")
+        }
+    }
+}
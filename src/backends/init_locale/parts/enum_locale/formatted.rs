@@ -0,0 +1,28 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn formatted() -> TokenStream {
+    quote! {
+        pub struct Formatted<A> {
+            format: fn(&A, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+            arguments: A,
+        }
+
+        impl<A> Formatted<A> {
+            #[inline]
+            pub const fn new(
+                format: fn(&A, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                arguments: A,
+            ) -> Self {
+                Self { format, arguments }
+            }
+        }
+
+        impl<A> core::fmt::Display for Formatted<A> {
+            #[inline]
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                (self.format)(&self.arguments, formatter)
+            }
+        }
+    }
+}
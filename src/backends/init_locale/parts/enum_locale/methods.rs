@@ -11,6 +11,7 @@ pub fn methods(arguments: &Arguments) -> SynResult<TokenStream> {
     let variants = &arguments.variants;
     let variants_label = &arguments.variants_label;
     let variants_number = arguments.variants.len();
+    let indices = 0..variants_number;
 
     let iterator_path = NamesProvider::get_path("core::iter::Iterator")?;
     let option_path = NamesProvider::get_path("core::option::Option")?;
@@ -59,6 +60,24 @@ pub fn methods(arguments: &Arguments) -> SynResult<TokenStream> {
                 Self::from_usize(value).unwrap_or_default()
             }
 
+            #[inline]
+            pub const fn to_index(self) -> usize {
+                match self {
+                    #(
+                        Self::#variants => #indices
+                    ),*
+                }
+            }
+
+            #[inline]
+            pub const fn from_index(index: usize) -> #option_path<Self> {
+                if index < Self::COUNT {
+                    #some_path(Self::VARIANTS[index])
+                } else {
+                    #none_path
+                }
+            }
+
             #[inline]
             pub const fn to_str(self) -> &'static str {
                 match self {
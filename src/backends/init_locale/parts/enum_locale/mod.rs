@@ -1,33 +1,66 @@
 mod r#enum;
+mod export;
+mod formatted;
 mod methods;
+mod negotiation;
+mod posix;
+mod tags;
 mod traits;
 
 use crate::{
     backends::init_locale::{
         arguments::Arguments,
-        parts::enum_locale::{r#enum::r#enum, methods::methods, traits::traits},
+        parts::enum_locale::{
+            r#enum::r#enum, export::export, formatted::formatted, methods::methods,
+            negotiation::negotiation, posix::posix, tags::tags, traits::traits,
+        },
+    },
+    utils::{
+        NamesProvider,
+        aliases::SynResult,
+        names::{MOD_LOCALE, MOD_PRIVATE},
     },
-    utils::{NamesProvider, aliases::SynResult},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
 
-pub fn enum_locale(arguments: &Arguments) -> SynResult<TokenStream> {
+pub fn enum_locale(
+    arguments: &Arguments,
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
     let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+    let locale_mod_ident = NamesProvider::get_name(MOD_LOCALE);
+    let private_mod_ident = NamesProvider::get_name(MOD_PRIVATE);
 
     let r#enum = r#enum(arguments);
     let methods = methods(arguments)?;
+    let tags = tags(arguments)?;
+    let negotiation = negotiation(arguments)?;
+    let posix = posix(arguments)?;
     let traits = traits(arguments)?;
+    let formatted = formatted();
+    let export = export(arguments, names_provider);
 
     Ok(quote! {
-        mod __locale {
+        mod #locale_mod_ident {
             use super::*;
 
             #r#enum
             #methods
+            #tags
+            #negotiation
+            #posix
             #traits
         }
 
-        pub use __locale::#locale_ident;
+        pub use #locale_mod_ident::#locale_ident;
+
+        #[doc(hidden)]
+        pub mod #private_mod_ident {
+            use super::#locale_ident;
+
+            #formatted
+            #export
+        }
     })
 }
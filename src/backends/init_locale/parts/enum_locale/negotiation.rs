@@ -0,0 +1,131 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn negotiation(arguments: &Arguments) -> SynResult<TokenStream> {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    let iterator_path = NamesProvider::get_path("core::iter::Iterator")?;
+    let option_path = NamesProvider::get_path("core::option::Option")?;
+    let some_path = NamesProvider::get_path("core::option::Option::Some")?;
+    let none_path = NamesProvider::get_path("core::option::Option::None")?;
+
+    Ok(quote! {
+        impl #locale_ident {
+            pub fn try_negotiate(header: &str) -> #option_path<Self> {
+                let mut excluded = [false; Self::COUNT];
+                for (range, _) in accept_language(header).filter(|(_, quality)| *quality == 0) {
+                    if let #some_path((language, script, region)) = language_subtags(range) {
+                        for (i, (variant_language, variant_script, variant_region)) in Self::SUBTAGS.into_iter().enumerate() {
+                            excluded[i] |= language.eq_ignore_ascii_case(variant_language)
+                                && (script.is_empty() || script.eq_ignore_ascii_case(variant_script))
+                                && (region.is_empty() || region.eq_ignore_ascii_case(variant_region));
+                        }
+                    }
+                }
+
+                let mut best: #option_path<(usize, u16)> = #none_path;
+                for (range, quality) in accept_language(header) {
+                    if quality == 0 || best.is_some_and(|(_, best_quality)| best_quality >= quality) {
+                        continue;
+                    }
+
+                    let candidate = if range == "*" {
+                        let default = Self::VARIANTS
+                            .iter()
+                            .position(|variant| *variant == Self::DEFAULT)
+                            .filter(|i| !excluded[*i]);
+
+                        default.or((0..Self::COUNT).find(|i| !excluded[*i]))
+                    } else {
+                        Self::match_range(range, &excluded)
+                    };
+
+                    if let #some_path(i) = candidate {
+                        best = #some_path((i, quality));
+                    }
+                }
+
+                best.map(|(i, _)| Self::VARIANTS[i])
+            }
+
+            #[inline]
+            pub fn negotiate(header: &str) -> Self {
+                Self::try_negotiate(header).unwrap_or_default()
+            }
+
+            fn match_range(range: &str, excluded: &[bool; Self::COUNT]) -> #option_path<usize> {
+                let (language, script, region) = language_subtags(range)?;
+                let compatible = |subtag: &str, variant_subtag: &str| {
+                    subtag.is_empty() || variant_subtag.is_empty() || subtag.eq_ignore_ascii_case(variant_subtag)
+                };
+
+                let mut best: #option_path<(usize, u8)> = #none_path;
+                for (i, (variant_language, variant_script, variant_region)) in Self::SUBTAGS.into_iter().enumerate() {
+                    if excluded[i] || !language.eq_ignore_ascii_case(variant_language) {
+                        continue;
+                    }
+
+                    let level = if script.eq_ignore_ascii_case(variant_script)
+                        && region.eq_ignore_ascii_case(variant_region)
+                    {
+                        3
+                    } else if compatible(script, variant_script) && compatible(region, variant_region) {
+                        2
+                    } else {
+                        1
+                    };
+
+                    if best.is_none_or(|(_, best_level)| level > best_level) {
+                        best = #some_path((i, level));
+                    }
+                }
+
+                best.map(|(i, _)| i)
+            }
+        }
+
+        fn accept_language(header: &str) -> impl #iterator_path<Item = (&str, u16)> {
+            header.split(',').filter_map(|item| {
+                let mut parameters = item.split(';');
+                let range = parameters.next()?.trim();
+                if range.is_empty() {
+                    return #none_path;
+                }
+
+                let mut quality = 1000;
+                for parameter in parameters {
+                    let (name, value) = parameter.split_once('=')?;
+                    if name.trim().eq_ignore_ascii_case("q") {
+                        quality = accept_language_quality(value.trim())?;
+                    }
+                }
+
+                #some_path((range, quality))
+            })
+        }
+
+        fn accept_language_quality(value: &str) -> #option_path<u16> {
+            let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+            if fraction.len() > 3 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+                return #none_path;
+            }
+
+            let mut quality = match integer {
+                "0" => 0,
+                "1" => 1000,
+                _ => return #none_path,
+            };
+            let mut scale = 100;
+            for digit in fraction.bytes() {
+                quality += u16::from(digit - b'0') * scale;
+                scale /= 10;
+            }
+
+            (quality <= 1000).then_some(quality)
+        }
+    })
+}
@@ -0,0 +1,72 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, language_tag::LanguageTag},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn posix(arguments: &Arguments) -> SynResult<TokenStream> {
+    if !arguments.env {
+        return Ok(TokenStream::new());
+    }
+
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+    let variants = &arguments.variants;
+    let variants_posix = arguments.variants_tag.iter().map(|tag| posix_locale(tag));
+
+    let option_path = NamesProvider::get_path("core::option::Option")?;
+
+    Ok(quote! {
+        impl #locale_ident {
+            pub fn from_posix(value: &str) -> #option_path<Self> {
+                value
+                    .split(':')
+                    .map(|locale| locale.split(['.', '@']).next().unwrap_or_default())
+                    .filter(|locale| !locale.is_empty() && *locale != "C" && *locale != "POSIX")
+                    .find_map(|locale| Self::match_range(locale, &[false; Self::COUNT]))
+                    .map(|i| Self::VARIANTS[i])
+            }
+
+            pub fn from_env() -> Self {
+                ["LC_ALL", "LC_MESSAGES", "LANGUAGE", "LANG"]
+                    .into_iter()
+                    .filter_map(|name| ::std::env::var(name).ok())
+                    .find(|value| !value.is_empty())
+                    .and_then(|value| Self::from_posix(&value))
+                    .unwrap_or_default()
+            }
+
+            #[inline]
+            pub const fn to_posix(self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variants => #variants_posix
+                    ),*
+                }
+            }
+        }
+    })
+}
+
+fn posix_locale(tag: &str) -> String {
+    let Some(tag) = LanguageTag::parse(tag) else {
+        return tag.to_string();
+    };
+
+    let mut posix = tag.language;
+    if let Some(region) = tag.region {
+        posix = format!("{posix}_{region}");
+    }
+    posix.push_str(".UTF-8");
+    if let Some(script) = tag.script {
+        let modifier = match script.as_str() {
+            "Latn" => "latin".to_string(),
+            "Cyrl" => "cyrillic".to_string(),
+            _ => script.to_ascii_lowercase(),
+        };
+
+        posix = format!("{posix}@{modifier}");
+    }
+
+    posix
+}
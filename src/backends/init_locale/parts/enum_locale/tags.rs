@@ -0,0 +1,90 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, language_tag::LanguageTag},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn tags(arguments: &Arguments) -> SynResult<TokenStream> {
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+    let variants = &arguments.variants;
+    let variants_tag = &arguments.variants_tag;
+    let (languages, scripts, regions) = variants_tag
+        .iter()
+        .map(|tag| {
+            let [language, script, region] = LanguageTag::matching_subtags(tag);
+
+            (language, script, region)
+        })
+        .collect::<(Vec<_>, Vec<_>, Vec<_>)>();
+
+    let option_path = NamesProvider::get_path("core::option::Option")?;
+    let some_path = NamesProvider::get_path("core::option::Option::Some")?;
+
+    Ok(quote! {
+        impl #locale_ident {
+            pub const TAGS: [&'static str; Self::COUNT] = [#(#variants_tag),*];
+            const SUBTAGS: [(&'static str, &'static str, &'static str); Self::COUNT] =
+                [#((#languages, #scripts, #regions)),*];
+
+            #[inline]
+            pub const fn to_tag(self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variants => #variants_tag
+                    ),*
+                }
+            }
+
+            pub fn from_tag(tag: &str) -> #option_path<Self> {
+                let (language, script, region) = language_subtags(tag)?;
+
+                Self::iter_variants()
+                    .zip(Self::SUBTAGS)
+                    .find(|(_, (variant_language, variant_script, variant_region))| {
+                        language.eq_ignore_ascii_case(variant_language)
+                            && script.eq_ignore_ascii_case(variant_script)
+                            && region.eq_ignore_ascii_case(variant_region)
+                    })
+                    .map(|(variant, _)| variant)
+            }
+
+            #[inline]
+            pub fn from_tag_or_default(tag: &str) -> Self {
+                Self::from_tag(tag).unwrap_or_default()
+            }
+        }
+
+        fn language_subtags(tag: &str) -> #option_path<(&str, &str, &str)> {
+            let mut subtags = tag.split(['-', '_']);
+
+            let language = subtags.next().filter(|language| {
+                core::matches!(language.len(), 2..=3 | 5..=8)
+                    && language.bytes().all(|byte| byte.is_ascii_alphabetic())
+            })?;
+
+            let mut subtag = subtags.next();
+            let script = match subtag {
+                #some_path(script)
+                    if script.len() == 4 && script.bytes().all(|byte| byte.is_ascii_alphabetic()) =>
+                {
+                    subtag = subtags.next();
+
+                    script
+                }
+                _ => "",
+            };
+            let region = match subtag {
+                #some_path(region)
+                    if (region.len() == 2 && region.bytes().all(|byte| byte.is_ascii_alphabetic()))
+                        || (region.len() == 3 && region.bytes().all(|byte| byte.is_ascii_digit())) =>
+                {
+                    region
+                }
+                _ => "",
+            };
+
+            #some_path((language, script, region))
+        }
+    })
+}
@@ -0,0 +1,45 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, names::MACRO_EXPORT_CSV, path_argument},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn macro_export_csv(
+    arguments: &Arguments,
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
+    Ok(if cfg!(feature = "from_csv") {
+        let export_csv_ident = NamesProvider::get_name(MACRO_EXPORT_CSV);
+        let export_csv_hashed_ident = names_provider.get_hashed_name(MACRO_EXPORT_CSV);
+        let localize_it_crate = names_provider.get_crate_name("localize_it")?;
+
+        let path_argument = path_argument(arguments.path.clone());
+
+        quote! {
+            #[macro_export]
+            macro_rules! #export_csv_hashed_ident {
+                ($writer: expr, [$($expression: path),* $(,)?] $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_csv,
+                        writer = $writer,
+                        expressions = [$($expression),*],
+                        #path_argument
+                    )
+                };
+
+                ($writer: expr $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_csv,
+                        writer = $writer,
+                        #path_argument
+                    )
+                };
+            }
+
+            pub use #export_csv_hashed_ident as #export_csv_ident;
+        }
+    } else {
+        TokenStream::new()
+    })
+}
@@ -0,0 +1,47 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, names::MACRO_EXPORT_JSON, path_argument},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn macro_export_json(
+    arguments: &Arguments,
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
+    Ok(if cfg!(feature = "from_json") {
+        let export_json_ident = NamesProvider::get_name(MACRO_EXPORT_JSON);
+        let export_json_hashed_ident = names_provider.get_hashed_name(MACRO_EXPORT_JSON);
+        let localize_it_crate = names_provider.get_crate_name("localize_it")?;
+
+        let path_argument = path_argument(arguments.path.clone());
+
+        quote! {
+            #[macro_export]
+            macro_rules! #export_json_hashed_ident {
+                ($writer: expr, $locale: expr, [$($expression: path),* $(,)?] $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_json,
+                        writer = $writer,
+                        locale = $locale,
+                        expressions = [$($expression),*],
+                        #path_argument
+                    )
+                };
+
+                ($writer: expr, $locale: expr $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_json,
+                        writer = $writer,
+                        locale = $locale,
+                        #path_argument
+                    )
+                };
+            }
+
+            pub use #export_json_hashed_ident as #export_json_ident;
+        }
+    } else {
+        TokenStream::new()
+    })
+}
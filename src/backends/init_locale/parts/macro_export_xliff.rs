@@ -0,0 +1,47 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, names::MACRO_EXPORT_XLIFF, path_argument},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn macro_export_xliff(
+    arguments: &Arguments,
+    names_provider: &NamesProvider,
+) -> SynResult<TokenStream> {
+    Ok(if cfg!(feature = "from_xliff") {
+        let export_xliff_ident = NamesProvider::get_name(MACRO_EXPORT_XLIFF);
+        let export_xliff_hashed_ident = names_provider.get_hashed_name(MACRO_EXPORT_XLIFF);
+        let localize_it_crate = names_provider.get_crate_name("localize_it")?;
+
+        let path_argument = path_argument(arguments.path.clone());
+
+        quote! {
+            #[macro_export]
+            macro_rules! #export_xliff_hashed_ident {
+                ($writer: expr, $locale: expr, [$($expression: path),* $(,)?] $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_xliff,
+                        writer = $writer,
+                        locale = $locale,
+                        expressions = [$($expression),*],
+                        #path_argument
+                    )
+                };
+
+                ($writer: expr, $locale: expr $(,)?) => {
+                    #localize_it_crate::__export!(
+                        function = write_xliff,
+                        writer = $writer,
+                        locale = $locale,
+                        #path_argument
+                    )
+                };
+            }
+
+            pub use #export_xliff_hashed_ident as #export_xliff_ident;
+        }
+    } else {
+        TokenStream::new()
+    })
+}
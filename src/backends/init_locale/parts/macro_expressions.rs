@@ -13,22 +13,8 @@ pub fn macro_expressions(names_provider: &NamesProvider) -> TokenStream {
     quote! {
         #[macro_export]
         macro_rules! #expressions_hashed_ident {
-            (
-                $(
-                    $name: ident $(: $r#type: ty)? => {
-                        $(
-                            $locale: ident: $value: expr
-                        ),+ $(,)?
-                    }
-                ),+ $(,)?
-            ) => {
-                $(
-                    #expression_path!($name $(: $r#type)? => {
-                        $(
-                            $locale: $value
-                        ),+
-                    });
-                )+
+            ($($expressions: tt)+) => {
+                #expression_path!($($expressions)+);
             };
         }
 
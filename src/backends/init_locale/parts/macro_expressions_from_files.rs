@@ -20,6 +20,7 @@ pub fn macro_expressions_from_files(
         let localize_it_crate = names_provider.get_crate_name("localize_it")?;
         let locale_name = &arguments.locale_name;
 
+        let locale_arguments = arguments.locale_arguments();
         let path_argument = path_argument(arguments.path.clone());
 
         quote! {
@@ -45,6 +46,7 @@ pub fn macro_expressions_from_files(
                         locales_path = [$($path),+],
                         expressions = [$($value),+],
                         expressions_type = [$(#expressions_from_files_path!(@type_or_default $($r#type)?)),+],
+                        #locale_arguments
                         #path_argument
                         locale_name = #locale_name,
                     );
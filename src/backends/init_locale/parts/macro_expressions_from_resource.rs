@@ -0,0 +1,45 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, aliases::SynResult, path_argument},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn macro_expressions_from_resource(
+    arguments: &Arguments,
+    names_provider: &NamesProvider,
+    enabled: bool,
+    name: &str,
+    backend: &str,
+) -> SynResult<TokenStream> {
+    Ok(if enabled {
+        let expressions_from_resource_ident = NamesProvider::get_name(name);
+        let expressions_from_resource_hashed_ident = names_provider.get_hashed_name(name);
+        let backend_ident = NamesProvider::get_name(backend);
+        let localize_it_crate = names_provider.get_crate_name("localize_it")?;
+        let default = &arguments.default;
+        let locale_name = &arguments.locale_name;
+
+        let locale_arguments = arguments.locale_arguments();
+        let path_argument = path_argument(arguments.path.clone());
+
+        quote! {
+            #[macro_export]
+            macro_rules! #expressions_from_resource_hashed_ident {
+                ($file: literal $(,)?) => {
+                    #localize_it_crate::#backend_ident!(
+                        file = $file,
+                        default = #default,
+                        #locale_arguments
+                        #path_argument
+                        locale_name = #locale_name,
+                    );
+                };
+            }
+
+            pub use #expressions_from_resource_hashed_ident as #expressions_from_resource_ident;
+        }
+    } else {
+        TokenStream::new()
+    })
+}
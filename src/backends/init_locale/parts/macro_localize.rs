@@ -1,8 +1,8 @@
 use crate::{
-    backends::init_locale::arguments::Arguments,
+    backends::init_locale::arguments::{Arguments, Storage},
     utils::{
         NamesProvider,
-        names::{MACRO_LOCALIZE, MOD_STORAGE},
+        names::{MACRO_LOCALIZE, MOD_CONTEXT, MOD_PRIVATE, MOD_STORAGE},
     },
 };
 use proc_macro2::TokenStream;
@@ -11,13 +11,47 @@ use quote::quote;
 pub fn macro_localize(arguments: &Arguments, names_provider: &NamesProvider) -> TokenStream {
     let localize_ident = NamesProvider::get_name(MACRO_LOCALIZE);
     let localize_hashed_ident = names_provider.get_hashed_name(MACRO_LOCALIZE);
+    let private_mod_path = names_provider.get_component_path(MOD_PRIVATE);
+    let context_mod_path = names_provider.get_component_path(MOD_CONTEXT);
 
-    let locale_from_storage = if arguments.storage {
-        let storage_path = names_provider.get_component_path(MOD_STORAGE);
+    let storage_path = names_provider.get_component_path(MOD_STORAGE);
+    let current_storage_path = match &arguments.storage {
+        Storage::Named(slots) => {
+            let slot = &slots[0];
 
+            quote!(#storage_path::#slot)
+        }
+        _ => quote!(#storage_path),
+    };
+
+    let locale_from_storage = if arguments.storage.is_enabled() {
         quote! {
+            ($($expression: ident)::+ => {$($name: ident: $argument: expr),* $(,)?} $(,)?) => {
+                #private_mod_path::Formatted::new(
+                    $($expression)::+[#current_storage_path::get().to_index()],
+                    $($expression)::+ {$($name: $argument),*},
+                )
+            };
+
             ($expression: expr $(=> [$($argument: expr),* $(,)?])? $(,)?) => {
-                $expression[#storage_path::get_as_usize()]$(($($argument),*))?
+                $expression[#current_storage_path::get().to_index()]$(($($argument),*))?
+            };
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let locale_from_slot = if let Storage::Named(_) = &arguments.storage {
+        quote! {
+            ($($expression: ident)::+ => {$($name: ident: $argument: expr),* $(,)?}, @$slot: ident $(,)?) => {
+                #private_mod_path::Formatted::new(
+                    $($expression)::+[#storage_path::$slot::get().to_index()],
+                    $($expression)::+ {$($name: $argument),*},
+                )
+            };
+
+            ($expression: expr $(=> [$($argument: expr),* $(,)?])?, @$slot: ident $(,)?) => {
+                $expression[#storage_path::$slot::get().to_index()]$(($($argument),*))?
             };
         }
     } else {
@@ -27,10 +61,29 @@ pub fn macro_localize(arguments: &Arguments, names_provider: &NamesProvider) ->
     quote! {
         #[macro_export]
         macro_rules! #localize_hashed_ident {
+            ($($expression: ident)::+ => {$($name: ident: $argument: expr),* $(,)?}, in $context: expr $(,)?) => {
+                #private_mod_path::Formatted::new(
+                    $($expression)::+[#context_mod_path::CurrentLocale::current_locale(&$context).to_index()],
+                    $($expression)::+ {$($name: $argument),*},
+                )
+            };
+
+            ($expression: expr $(=> [$($argument: expr),* $(,)?])?, in $context: expr $(,)?) => {
+                $expression[#context_mod_path::CurrentLocale::current_locale(&$context).to_index()]$(($($argument),*))?
+            };
+
             #locale_from_storage
+            #locale_from_slot
+
+            ($($expression: ident)::+ => {$($name: ident: $argument: expr),* $(,)?}, $locale: expr $(,)?) => {
+                #private_mod_path::Formatted::new(
+                    $($expression)::+[$locale.to_index()],
+                    $($expression)::+ {$($name: $argument),*},
+                )
+            };
 
             ($expression: expr $(=> [$($argument: expr),* $(,)?])?, $locale: expr $(,)?) => {
-                $expression[$locale.to_usize()]$(($($argument),*))?
+                $expression[$locale.to_index()]$(($($argument),*))?
             };
         }
 
@@ -1,17 +1,31 @@
 mod enum_locale;
+mod macro_export_csv;
+mod macro_export_json;
+mod macro_export_pot;
+mod macro_export_xliff;
 mod macro_expression;
 mod macro_expression_part;
 mod macro_expressions;
 mod macro_expressions_from_files;
+mod macro_expressions_from_resource;
 mod macro_expressions_part;
 mod macro_localize;
+mod mod_context;
+mod mod_plural;
 mod mod_storage;
 
 pub use enum_locale::enum_locale;
+pub use macro_export_csv::macro_export_csv;
+pub use macro_export_json::macro_export_json;
+pub use macro_export_pot::macro_export_pot;
+pub use macro_export_xliff::macro_export_xliff;
 pub use macro_expression::macro_expression;
 pub use macro_expression_part::macro_expression_part;
 pub use macro_expressions::macro_expressions;
 pub use macro_expressions_from_files::macro_expressions_from_files;
+pub use macro_expressions_from_resource::macro_expressions_from_resource;
 pub use macro_expressions_part::macro_expressions_part;
 pub use macro_localize::macro_localize;
+pub use mod_context::mod_context;
+pub use mod_plural::mod_plural;
 pub use mod_storage::mod_storage;
@@ -0,0 +1,62 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{NamesProvider, names::MOD_CONTEXT},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn mod_context(arguments: &Arguments) -> TokenStream {
+    let context_ident = NamesProvider::get_name(MOD_CONTEXT);
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+
+    quote! {
+        pub mod #context_ident {
+            use super::#locale_ident;
+
+            pub trait CurrentLocale {
+                fn current_locale(&self) -> #locale_ident;
+            }
+
+            impl CurrentLocale for #locale_ident {
+                #[inline]
+                fn current_locale(&self) -> #locale_ident {
+                    *self
+                }
+            }
+
+            impl<T: CurrentLocale + ?core::marker::Sized> CurrentLocale for &T {
+                #[inline]
+                fn current_locale(&self) -> #locale_ident {
+                    T::current_locale(self)
+                }
+            }
+
+            #[derive(
+                core::fmt::Debug,
+                core::clone::Clone,
+                core::marker::Copy,
+                core::cmp::PartialEq,
+                core::cmp::Eq,
+                core::hash::Hash,
+                core::default::Default,
+            )]
+            pub struct LocaleContext {
+                pub locale: #locale_ident,
+            }
+
+            impl LocaleContext {
+                #[inline]
+                pub const fn new(locale: #locale_ident) -> Self {
+                    Self { locale }
+                }
+            }
+
+            impl CurrentLocale for LocaleContext {
+                #[inline]
+                fn current_locale(&self) -> #locale_ident {
+                    self.locale
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    backends::init_locale::arguments::Arguments,
+    utils::{
+        NamesProvider,
+        language_tag::LanguageTag,
+        names::MOD_PLURAL,
+        plural_rules::{PluralRules, category_ident},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn mod_plural(arguments: &Arguments) -> TokenStream {
+    let plural_ident = NamesProvider::get_name(MOD_PLURAL);
+    let locale_ident = NamesProvider::get_name(&arguments.locale_name);
+    let variants = &arguments.variants;
+    let (operands, categories) = arguments
+        .variants_tag
+        .iter()
+        .map(|tag| {
+            let [language, ..] = LanguageTag::matching_subtags(tag);
+            let conditions = PluralRules::new(&language)
+                .map(|rules| rules.conditions().collect::<Vec<_>>())
+                .unwrap_or_default();
+            let operands = if conditions.is_empty() {
+                quote!(_)
+            } else {
+                quote!(operands)
+            };
+            let conditions = conditions.into_iter().map(|(category, condition)| {
+                let category = category_ident(category);
+
+                quote! {
+                    if #condition {
+                        PluralCategory::#category
+                    } else
+                }
+            });
+
+            (
+                operands,
+                quote! {
+                    #(#conditions)* {
+                        PluralCategory::Other
+                    }
+                },
+            )
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let conversions = [
+        (quote!(u8), quote!(Self::integer(u64::from(number)))),
+        (quote!(u16), quote!(Self::integer(u64::from(number)))),
+        (quote!(u32), quote!(Self::integer(u64::from(number)))),
+        (quote!(u64), quote!(Self::integer(number))),
+        (quote!(u128), quote!(Self::wide(number))),
+        (quote!(usize), quote!(Self::wide(number as u128))),
+        (
+            quote!(i8),
+            quote!(Self::integer(u64::from(number.unsigned_abs()))),
+        ),
+        (
+            quote!(i16),
+            quote!(Self::integer(u64::from(number.unsigned_abs()))),
+        ),
+        (
+            quote!(i32),
+            quote!(Self::integer(u64::from(number.unsigned_abs()))),
+        ),
+        (quote!(i64), quote!(Self::integer(number.unsigned_abs()))),
+        (quote!(i128), quote!(Self::wide(number.unsigned_abs()))),
+        (
+            quote!(isize),
+            quote!(Self::wide(number.unsigned_abs() as u128)),
+        ),
+        (
+            quote!(f32),
+            quote!(Self::decimal(f64::from(number.abs()), number)),
+        ),
+        (quote!(f64), quote!(Self::decimal(number.abs(), number))),
+    ];
+    let (types, conversions) = conversions.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+
+    quote! {
+        pub mod #plural_ident {
+            use super::#locale_ident;
+
+            #[derive(
+                core::fmt::Debug,
+                core::clone::Clone,
+                core::marker::Copy,
+                core::cmp::PartialEq,
+                core::cmp::Eq,
+                core::hash::Hash,
+            )]
+            pub enum PluralCategory {
+                Zero,
+                One,
+                Two,
+                Few,
+                Many,
+                Other,
+            }
+
+            #[derive(core::fmt::Debug, core::clone::Clone, core::marker::Copy, core::cmp::PartialEq)]
+            pub struct PluralOperands {
+                pub n: f64,
+                pub i: u64,
+                pub v: u64,
+                pub f: u64,
+                pub t: u64,
+            }
+
+            impl PluralOperands {
+                const LAST_DIGITS: u64 = 1_000_000_000_000_000_000;
+
+                #[inline]
+                const fn integer(i: u64) -> Self {
+                    Self {
+                        n: i as f64,
+                        i,
+                        v: 0,
+                        f: 0,
+                        t: 0,
+                    }
+                }
+
+                // Numbers beyond `u64` keep their last 18 digits above `LAST_DIGITS`, which
+                // preserves the `mod` relations of the rules and fails comparisons with small values
+                fn wide(number: u128) -> Self {
+                    match u64::try_from(number) {
+                        core::result::Result::Ok(i) => Self::integer(i),
+                        core::result::Result::Err(_) => Self {
+                            n: number as f64,
+                            i: u64::try_from(number % u128::from(Self::LAST_DIGITS))
+                                .map_or(u64::MAX, |digits| Self::LAST_DIGITS + digits),
+                            ..Self::integer(0)
+                        },
+                    }
+                }
+
+                fn decimal(n: f64, number: impl core::fmt::Display) -> Self {
+                    struct Digits {
+                        operands: PluralOperands,
+                        fraction: bool,
+                    }
+
+                    impl core::fmt::Write for Digits {
+                        fn write_str(&mut self, str: &str) -> core::fmt::Result {
+                            for byte in str.bytes() {
+                                match byte {
+                                    b'.' => self.fraction = true,
+                                    b'0'..=b'9' => {
+                                        let digit = u64::from(byte - b'0');
+                                        let operands = &mut self.operands;
+                                        if self.fraction {
+                                            operands.v += 1;
+                                            operands.f = operands.f.saturating_mul(10).saturating_add(digit);
+                                        } else {
+                                            operands.i = operands.i % PluralOperands::LAST_DIGITS * 10 + digit;
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            core::result::Result::Ok(())
+                        }
+                    }
+
+                    let mut digits = Digits {
+                        operands: Self::integer(0),
+                        fraction: false,
+                    };
+                    let _ = core::fmt::Write::write_fmt(&mut digits, core::format_args!("{}", number));
+
+                    let mut operands = digits.operands;
+                    operands.n = n;
+                    if n >= Self::LAST_DIGITS as f64 {
+                        operands.i = Self::LAST_DIGITS + operands.i % Self::LAST_DIGITS;
+                    }
+                    operands.t = operands.f;
+                    while operands.t != 0 && operands.t % 10 == 0 {
+                        operands.t /= 10;
+                    }
+
+                    operands
+                }
+            }
+
+            #(
+                impl core::convert::From<#types> for PluralOperands {
+                    #[inline]
+                    fn from(number: #types) -> Self {
+                        #conversions
+                    }
+                }
+            )*
+
+            impl #locale_ident {
+                pub fn plural_category(self, number: impl core::convert::Into<PluralOperands>) -> PluralCategory {
+                    let operands: PluralOperands = number.into();
+
+                    match (self, operands) {
+                        #(
+                            (Self::#variants, #operands) => #categories
+                        ),*
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,79 +1,173 @@
 use crate::{
-    backends::init_locale::arguments::Arguments,
+    backends::init_locale::arguments::{Arguments, Storage},
     utils::{NamesProvider, names::MOD_STORAGE},
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
+use syn::{Path, PathSegment};
 
 pub fn mod_storage(arguments: &Arguments) -> TokenStream {
     let storage_ident = NamesProvider::get_name(MOD_STORAGE);
+
+    match &arguments.storage {
+        Storage::Disabled => TokenStream::new(),
+        Storage::Named(slots) => {
+            let storage = storage(arguments, quote!(super::super));
+
+            quote! {
+                pub mod #storage_ident {
+                    #(
+                        pub mod #slots {
+                            #storage
+                        }
+                    )*
+                }
+            }
+        }
+        _ => {
+            let storage = storage(arguments, quote!(super));
+
+            quote! {
+                pub mod #storage_ident {
+                    #storage
+                }
+            }
+        }
+    }
+}
+
+fn storage(arguments: &Arguments, parent: TokenStream) -> TokenStream {
     let locale_ident = NamesProvider::get_name(&arguments.locale_name);
 
-    if arguments.storage {
-        quote! {
-            pub mod #storage_ident {
-                use super::#locale_ident;
-                use core::{
-                    result::Result,
-                    sync::atomic::{AtomicUsize, Ordering},
-                };
+    let current_locale = match &arguments.storage {
+        Storage::Disabled => return TokenStream::new(),
+        Storage::Global | Storage::Named(_) => {
+            let (atomic_cfg, test_storage) = if arguments.test_support {
+                (quote!(#[cfg(not(test))]), test_storage(&locale_ident))
+            } else {
+                (TokenStream::new(), TokenStream::new())
+            };
+
+            quote! {
+                #atomic_cfg
+                use core::sync::atomic::{AtomicUsize, Ordering};
 
+                #atomic_cfg
                 static CURRENT_LOCALE: AtomicUsize = AtomicUsize::new(#locale_ident::DEFAULT.to_usize());
 
+                #atomic_cfg
                 #[inline]
-                pub fn get() -> #locale_ident {
-                    #locale_ident::from_usize_or_default(CURRENT_LOCALE.load(Ordering::Relaxed))
+                fn load() -> usize {
+                    CURRENT_LOCALE.load(Ordering::Relaxed)
                 }
 
+                #atomic_cfg
+                #[cfg(target_has_atomic = "ptr")]
                 #[inline]
-                pub fn set(locale: #locale_ident) {
-                    CURRENT_LOCALE.store(locale.to_usize(), Ordering::Relaxed);
+                fn swap(value: usize) -> usize {
+                    CURRENT_LOCALE.swap(value, Ordering::Relaxed)
                 }
 
+                #atomic_cfg
+                #[cfg(not(target_has_atomic = "ptr"))]
                 #[inline]
-                pub fn get_as_usize() -> usize {
-                    get().to_usize()
+                fn swap(value: usize) -> usize {
+                    let previous = load();
+                    CURRENT_LOCALE.store(value, Ordering::Relaxed);
+                    previous
                 }
 
-                #[inline]
-                pub fn set_from_usize(value: usize) -> Result<(), &'static str> {
-                    #locale_ident::from_usize(value)
-                        .map(set)
-                        .ok_or("Invalid locale identifier")
-                }
+                #test_storage
 
                 #[inline]
-                pub fn set_from_usize_or_default(value: usize) {
-                    set(#locale_ident::from_usize_or_default(value));
+                pub fn reset() {
+                    set(#locale_ident::DEFAULT);
                 }
+            }
+        }
+        Storage::AtomicU8 => quote! {
+            use core::sync::atomic::{AtomicU8, Ordering};
 
-                #[inline]
-                pub fn get_as_str() -> &'static str {
-                    get().to_str()
+            const _: () = {
+                let mut index = 0;
+                while index < #locale_ident::COUNT {
+                    core::assert!(
+                        #locale_ident::VARIANTS[index].to_usize() <= u8::MAX as usize,
+                        "`storage = atomic_u8` supports locale identifiers up to 255"
+                    );
+                    index += 1;
                 }
+            };
 
-                #[inline]
-                pub fn set_from_str(str: &str) -> Result<(), &'static str> {
-                    #locale_ident::from_str(str)
-                        .map(set)
-                        .ok_or("Invalid locale identifier")
-                }
+            static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(#locale_ident::DEFAULT.to_usize() as u8);
 
-                #[inline]
-                pub fn set_from_str_or_default(str: &str) {
-                    set(#locale_ident::from_str_or_default(str));
+            #[inline]
+            fn load() -> usize {
+                CURRENT_LOCALE.load(Ordering::Relaxed) as usize
+            }
+
+            #[cfg(target_has_atomic = "8")]
+            #[inline]
+            fn swap(value: usize) -> usize {
+                CURRENT_LOCALE.swap(value as u8, Ordering::Relaxed) as usize
+            }
+
+            #[cfg(not(target_has_atomic = "8"))]
+            #[inline]
+            fn swap(value: usize) -> usize {
+                let previous = load();
+                CURRENT_LOCALE.store(value as u8, Ordering::Relaxed);
+                previous
+            }
+
+            #[inline]
+            pub fn reset() {
+                set(#locale_ident::DEFAULT);
+            }
+        },
+        Storage::UnsafeSingleCore => quote! {
+            static CURRENT_LOCALE: SingleCore<usize> = SingleCore::new(#locale_ident::DEFAULT.to_usize());
+
+            #[inline]
+            fn load() -> usize {
+                CURRENT_LOCALE.get()
+            }
+
+            #[inline]
+            fn swap(value: usize) -> usize {
+                CURRENT_LOCALE.replace(value)
+            }
+
+            #[inline]
+            pub fn reset() {
+                set(#locale_ident::DEFAULT);
+            }
+        },
+        Storage::Custom(path) => {
+            let path = parent_path(path);
+
+            quote! {
+                pub trait LocaleStorage {
+                    fn load() -> usize;
+
+                    fn store(value: usize);
+
+                    #[inline]
+                    fn swap(value: usize) -> usize {
+                        let previous = Self::load();
+                        Self::store(value);
+                        previous
+                    }
                 }
 
                 #[inline]
-                pub fn set_from_caseless_str(str: &str) -> Result<(), &'static str> {
-                    #locale_ident::from_caseless_str(str)
-                        .map(set)
-                        .ok_or("Invalid locale identifier")
+                fn load() -> usize {
+                    <#path as LocaleStorage>::load()
                 }
 
                 #[inline]
-                pub fn set_from_caseless_str_or_default(str: &str) {
-                    set(#locale_ident::from_caseless_str_or_default(str));
+                fn swap(value: usize) -> usize {
+                    <#path as LocaleStorage>::swap(value)
                 }
 
                 #[inline]
@@ -82,7 +176,489 @@ pub fn mod_storage(arguments: &Arguments) -> TokenStream {
                 }
             }
         }
+        Storage::ThreadLocal => quote! {
+            use core::{
+                cell::Cell,
+                sync::atomic::{AtomicUsize, Ordering},
+            };
+
+            static DEFAULT_LOCALE: AtomicUsize = AtomicUsize::new(#locale_ident::DEFAULT.to_usize());
+
+            ::std::thread_local! {
+                static CURRENT_LOCALE: Cell<usize> = Cell::new(DEFAULT_LOCALE.load(Ordering::Relaxed));
+            }
+
+            #[inline]
+            fn load() -> usize {
+                CURRENT_LOCALE.with(Cell::get)
+            }
+
+            #[inline]
+            fn swap(value: usize) -> usize {
+                CURRENT_LOCALE.with(|current| current.replace(value))
+            }
+
+            #[inline]
+            pub fn get_default() -> #locale_ident {
+                #locale_ident::from_usize_or_default(DEFAULT_LOCALE.load(Ordering::Relaxed))
+            }
+
+            #[inline]
+            pub fn set_default(locale: #locale_ident) {
+                DEFAULT_LOCALE.store(locale.to_usize(), Ordering::Relaxed);
+            }
+
+            #[inline]
+            pub fn reset() {
+                set(get_default());
+            }
+
+            pub fn spawn_with_current<F, T>(function: F) -> ::std::thread::JoinHandle<T>
+            where
+                F: FnOnce() -> T + Send + 'static,
+                T: Send + 'static,
+            {
+                let locale = get();
+
+                ::std::thread::spawn(move || {
+                    set(locale);
+                    function()
+                })
+            }
+        },
+    };
+
+    let test_guard = if arguments.test_support {
+        quote! {
+            static TEST_LOCK: ::std::sync::Mutex<()> = ::std::sync::Mutex::new(());
+
+            #[must_use = "the previous locale is restored when the guard is dropped"]
+            pub struct TestGuard {
+                _guard: Guard,
+                _lock: ::std::sync::MutexGuard<'static, ()>,
+            }
+
+            pub fn test_guard(locale: #locale_ident) -> TestGuard {
+                let lock = TEST_LOCK
+                    .lock()
+                    .unwrap_or_else(::std::sync::PoisonError::into_inner);
+
+                TestGuard {
+                    _guard: scoped(locale),
+                    _lock: lock,
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // `WithLocale` swaps the locale around every poll, so the swap must not be
+    // visible to futures polled on other threads at the same time.
+    let with_locale = if let Storage::ThreadLocal | Storage::Custom(_) = arguments.storage {
+        quote! {
+            use core::{
+                future::Future,
+                pin::Pin,
+                task::{Context, Poll},
+            };
+
+            #[must_use = "futures do nothing unless polled"]
+            pub struct WithLocale<F> {
+                future: F,
+                locale: #locale_ident,
+            }
+
+            impl<F: Future> Future for WithLocale<F> {
+                type Output = F::Output;
+
+                fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+                    // SAFETY: `future` is pinned together with the wrapper and is never moved out.
+                    let this = unsafe { self.get_unchecked_mut() };
+                    let future = unsafe { Pin::new_unchecked(&mut this.future) };
+
+                    let _guard = scoped(this.locale);
+                    let poll = future.poll(context);
+                    this.locale = get();
+
+                    poll
+                }
+            }
+
+            #[inline]
+            pub fn instrument<F: Future>(future: F, locale: #locale_ident) -> WithLocale<F> {
+                WithLocale { future, locale }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let notifications = if let Storage::UnsafeSingleCore = arguments.storage {
+        single_core_notifications(&locale_ident)
+    } else {
+        atomic_notifications(&locale_ident)
+    };
+
+    let init_from_env = if arguments.env {
+        quote! {
+            #[inline]
+            pub fn init_from_env() {
+                set(#locale_ident::from_env());
+            }
+        }
     } else {
         TokenStream::new()
+    };
+
+    let persistence = if arguments.persist {
+        quote! {
+            pub fn load_from(path: impl AsRef<::std::path::Path>) {
+                let locale = ::std::fs::read_to_string(path)
+                    .map_or(#locale_ident::DEFAULT, |content| {
+                        #locale_ident::from_tag_or_default(content.trim())
+                    });
+
+                set(locale);
+            }
+
+            pub fn save_to(path: impl AsRef<::std::path::Path>) -> ::std::io::Result<()> {
+                let path = path.as_ref();
+                let mut temporary = path.as_os_str().to_owned();
+                temporary.push(".tmp");
+
+                ::std::fs::write(&temporary, get().to_tag())?;
+                ::std::fs::rename(&temporary, path)
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        use #parent::#locale_ident;
+        use core::result::Result;
+
+        #current_locale
+
+        #notifications
+
+        #[inline]
+        fn exchange(locale: #locale_ident) -> #locale_ident {
+            #locale_ident::from_usize_or_default(swap(locale.to_usize()))
+        }
+
+        #[inline]
+        pub fn get() -> #locale_ident {
+            #locale_ident::from_usize_or_default(load())
+        }
+
+        #[inline]
+        pub fn set(locale: #locale_ident) {
+            replace(locale);
+        }
+
+        #[inline]
+        pub fn replace(locale: #locale_ident) -> #locale_ident {
+            let previous = exchange(locale);
+            if previous != locale {
+                advance_generation();
+                notify(previous, locale);
+            }
+
+            previous
+        }
+
+        #[inline]
+        pub fn get_as_usize() -> usize {
+            get().to_usize()
+        }
+
+        #[inline]
+        pub fn set_from_usize(value: usize) -> Result<(), &'static str> {
+            #locale_ident::from_usize(value)
+                .map(set)
+                .ok_or("Invalid locale identifier")
+        }
+
+        #[inline]
+        pub fn set_from_usize_or_default(value: usize) {
+            set(#locale_ident::from_usize_or_default(value));
+        }
+
+        #[inline]
+        pub fn get_as_str() -> &'static str {
+            get().to_str()
+        }
+
+        #[inline]
+        pub fn set_from_str(str: &str) -> Result<(), &'static str> {
+            #locale_ident::from_str(str)
+                .map(set)
+                .ok_or("Invalid locale identifier")
+        }
+
+        #[inline]
+        pub fn set_from_str_or_default(str: &str) {
+            set(#locale_ident::from_str_or_default(str));
+        }
+
+        #[inline]
+        pub fn set_from_caseless_str(str: &str) -> Result<(), &'static str> {
+            #locale_ident::from_caseless_str(str)
+                .map(set)
+                .ok_or("Invalid locale identifier")
+        }
+
+        #[inline]
+        pub fn set_from_caseless_str_or_default(str: &str) {
+            set(#locale_ident::from_caseless_str_or_default(str));
+        }
+
+        #[inline]
+        pub fn set_from_tag(tag: &str) -> Result<(), &'static str> {
+            #locale_ident::from_tag(tag)
+                .map(set)
+                .ok_or("Invalid locale identifier")
+        }
+
+        #[inline]
+        pub fn set_from_tag_or_default(tag: &str) {
+            set(#locale_ident::from_tag_or_default(tag));
+        }
+
+        #[inline]
+        pub fn set_from_accept_language(header: &str) -> Result<(), &'static str> {
+            #locale_ident::try_negotiate(header)
+                .map(set)
+                .ok_or("No acceptable locale")
+        }
+
+        #[inline]
+        pub fn set_from_accept_language_or_default(header: &str) {
+            set(#locale_ident::negotiate(header));
+        }
+
+        #init_from_env
+
+        #persistence
+
+        #[must_use = "the previous locale is restored when the guard is dropped"]
+        pub struct Guard {
+            previous: #locale_ident,
+        }
+
+        impl Drop for Guard {
+            #[inline]
+            fn drop(&mut self) {
+                exchange(self.previous);
+            }
+        }
+
+        #[inline]
+        pub fn scoped(locale: #locale_ident) -> Guard {
+            Guard {
+                previous: exchange(locale),
+            }
+        }
+
+        #[inline]
+        pub fn with<T>(locale: #locale_ident, function: impl FnOnce() -> T) -> T {
+            let _guard = scoped(locale);
+            function()
+        }
+
+        #test_guard
+
+        #with_locale
+    }
+}
+
+fn test_storage(locale_ident: &Ident) -> TokenStream {
+    quote! {
+        #[cfg(test)]
+        ::std::thread_local! {
+            static CURRENT_LOCALE: core::cell::Cell<usize> =
+                core::cell::Cell::new(#locale_ident::DEFAULT.to_usize());
+        }
+
+        #[cfg(test)]
+        #[inline]
+        fn load() -> usize {
+            CURRENT_LOCALE.with(core::cell::Cell::get)
+        }
+
+        #[cfg(test)]
+        #[inline]
+        fn swap(value: usize) -> usize {
+            CURRENT_LOCALE.with(|current| current.replace(value))
+        }
+    }
+}
+
+fn parent_path(path: &Path) -> Path {
+    let mut path = path.clone();
+    if path.leading_colon.is_some() || path.segments[0].ident == "crate" {
+        return path;
+    }
+
+    if path.segments[0].ident == "self" {
+        path.segments[0].ident = Ident::new("super", path.segments[0].ident.span());
+    } else {
+        path.segments
+            .insert(0, PathSegment::from(Ident::new("super", Span::call_site())));
+    }
+
+    path
+}
+
+fn atomic_notifications(locale_ident: &Ident) -> TokenStream {
+    quote! {
+        pub const MAX_LISTENERS: usize = 8;
+
+        static GENERATION: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+        static LISTENERS: [core::sync::atomic::AtomicPtr<()>; MAX_LISTENERS] =
+            [const { core::sync::atomic::AtomicPtr::new(core::ptr::null_mut()) }; MAX_LISTENERS];
+
+        #[cfg(target_has_atomic = "ptr")]
+        #[inline]
+        fn advance_generation() {
+            GENERATION.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }
+
+        #[cfg(not(target_has_atomic = "ptr"))]
+        #[inline]
+        fn advance_generation() {
+            use core::sync::atomic::Ordering;
+
+            GENERATION.store(GENERATION.load(Ordering::Relaxed).wrapping_add(1), Ordering::Relaxed);
+        }
+
+        fn notify(previous: #locale_ident, locale: #locale_ident) {
+            for listener in &LISTENERS {
+                let listener = listener.load(core::sync::atomic::Ordering::Acquire);
+                if !listener.is_null() {
+                    // SAFETY: only `fn(Locale, Locale)` pointers are stored by `subscribe`.
+                    let listener = unsafe {
+                        core::mem::transmute::<*mut (), fn(#locale_ident, #locale_ident)>(listener)
+                    };
+                    listener(previous, locale);
+                }
+            }
+        }
+
+        #[inline]
+        pub fn generation() -> usize {
+            GENERATION.load(core::sync::atomic::Ordering::Relaxed)
+        }
+
+        #[cfg(target_has_atomic = "ptr")]
+        pub fn subscribe(listener: fn(#locale_ident, #locale_ident)) -> Result<(), &'static str> {
+            use core::sync::atomic::Ordering;
+
+            let listener = listener as *mut ();
+
+            LISTENERS
+                .iter()
+                .any(|slot| {
+                    slot.compare_exchange(core::ptr::null_mut(), listener, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok()
+                })
+                .then_some(())
+                .ok_or("Too many listeners")
+        }
+
+        #[cfg(not(target_has_atomic = "ptr"))]
+        pub fn subscribe(listener: fn(#locale_ident, #locale_ident)) -> Result<(), &'static str> {
+            use core::sync::atomic::Ordering;
+
+            LISTENERS
+                .iter()
+                .find(|slot| slot.load(Ordering::Acquire).is_null())
+                .map(|slot| slot.store(listener as *mut (), Ordering::Release))
+                .ok_or("Too many listeners")
+        }
+    }
+}
+
+fn single_core_notifications(locale_ident: &Ident) -> TokenStream {
+    quote! {
+        struct SingleCore<T>(core::cell::Cell<T>);
+
+        // SAFETY: `storage = unsafe_single_core` requires a single core where no thread
+        // or interrupt preempts a storage function, so the cells are never accessed
+        // concurrently even though the statics are shared.
+        unsafe impl<T: Send> Sync for SingleCore<T> {}
+
+        impl<T: Copy> SingleCore<T> {
+            #[inline]
+            const fn new(value: T) -> Self {
+                Self(core::cell::Cell::new(value))
+            }
+
+            #[inline]
+            fn get(&self) -> T {
+                self.0.get()
+            }
+
+            #[inline]
+            fn replace(&self, value: T) -> T {
+                self.0.replace(value)
+            }
+        }
+
+        pub const MAX_LISTENERS: usize = 8;
+
+        static GENERATION: SingleCore<usize> = SingleCore::new(0);
+        static LISTENERS: [SingleCore<Option<fn(#locale_ident, #locale_ident)>>; MAX_LISTENERS] =
+            [const { SingleCore::new(None) }; MAX_LISTENERS];
+
+        #[inline]
+        fn advance_generation() {
+            GENERATION.replace(GENERATION.get().wrapping_add(1));
+        }
+
+        fn notify(previous: #locale_ident, locale: #locale_ident) {
+            for listener in LISTENERS.iter().filter_map(SingleCore::get) {
+                listener(previous, locale);
+            }
+        }
+
+        #[inline]
+        pub fn generation() -> usize {
+            GENERATION.get()
+        }
+
+        pub fn subscribe(listener: fn(#locale_ident, #locale_ident)) -> Result<(), &'static str> {
+            LISTENERS
+                .iter()
+                .find(|slot| slot.get().is_none())
+                .map(|slot| {
+                    slot.replace(Some(listener));
+                })
+                .ok_or("Too many listeners")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mod_storage;
+
+    fn generates(input: &str, item: &str) -> bool {
+        let arguments = syn::parse_str(input).unwrap();
+        mod_storage(&arguments)
+            .to_string()
+            .contains(&format!("fn {item}"))
+    }
+
+    #[test]
+    fn instrument() {
+        assert_eq!(generates("En, storage = thread_local", "instrument"), true);
+        assert_eq!(generates("En, storage = Backend", "instrument"), true);
+        assert_eq!(generates("En, storage = true", "instrument"), false);
+        assert_eq!(generates("En, storage = atomic_u8", "instrument"), false);
+        assert_eq!(generates("En, storage = [ui]", "instrument"), false);
     }
 }
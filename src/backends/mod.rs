@@ -4,8 +4,54 @@ mod init_locale;
 #[cfg(feature = "from_files")]
 mod expressions_from_files;
 
+#[cfg(feature = "from_ftl")]
+mod expressions_from_ftl;
+
+#[cfg(feature = "from_po")]
+mod expressions_from_po;
+
+#[cfg(feature = "from_json")]
+mod expressions_from_json;
+
+#[cfg(feature = "from_csv")]
+mod expressions_from_csv;
+
+#[cfg(feature = "from_xliff")]
+mod expressions_from_xliff;
+
+#[cfg(any(
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod export;
+
 pub use expression::expression_backend;
 pub use init_locale::init_locale_backend;
 
 #[cfg(feature = "from_files")]
 pub use expressions_from_files::expressions_from_files_backend;
+
+#[cfg(feature = "from_ftl")]
+pub use expressions_from_ftl::expressions_from_ftl_backend;
+
+#[cfg(feature = "from_po")]
+pub use expressions_from_po::expressions_from_po_backend;
+
+#[cfg(feature = "from_json")]
+pub use expressions_from_json::expressions_from_json_backend;
+
+#[cfg(feature = "from_csv")]
+pub use expressions_from_csv::expressions_from_csv_backend;
+
+#[cfg(feature = "from_xliff")]
+pub use expressions_from_xliff::expressions_from_xliff_backend;
+
+#[cfg(any(
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use export::export_backend;
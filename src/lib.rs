@@ -12,14 +12,68 @@ use proc_macro::TokenStream;
 #[cfg(feature = "from_files")]
 use crate::backends::expressions_from_files_backend;
 
+#[cfg(feature = "from_ftl")]
+use crate::backends::expressions_from_ftl_backend;
+
+#[cfg(feature = "from_po")]
+use crate::backends::expressions_from_po_backend;
+
+#[cfg(feature = "from_json")]
+use crate::backends::expressions_from_json_backend;
+
+#[cfg(feature = "from_csv")]
+use crate::backends::expressions_from_csv_backend;
+
+#[cfg(feature = "from_xliff")]
+use crate::backends::expressions_from_xliff_backend;
+
+#[cfg(any(
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+use crate::backends::export_backend;
+
 /// Initializes the localization system.
 ///
 /// # Arguments
 ///
 /// * **Unnamed arguments** — locale variants. At least one must be provided.
 ///   Optionally, a custom label can be specified using `=>`. If no label is
-///   provided, it defaults to the variant name.
+///   provided, it defaults to the variant name. A BCP 47 language tag can be
+///   specified in parentheses as `(tag = "en-US")`. If no tag is provided, it
+///   is derived from the variant name split at case changes, so `EnGb` becomes
+///   `en-GB` and `ZhHantTw` becomes `zh-Hant-TW`. A variant name that does not
+///   form a valid tag, such as `English`, uses its lowercased name (`english`).
+///   Only explicit tags are checked to be valid and unique. A stable identifier can be
+///   specified as `En = 1`, which is returned by `to_usize` and used by storage
+///   instead of the position of the variant. Variants without an identifier
+///   follow the previous one, as in Rust enums.
 /// * `storage` — whether to generate storage for the current locale. Default to `false`.
+///   `storage = thread_local` generates the same storage with a separate current
+///   locale for each thread. Requires `std`.
+///   For targets without pointer-sized atomics, `storage = atomic_u8` stores the
+///   current locale in an `AtomicU8`, and `storage = unsafe_single_core` stores it
+///   in a `Cell` that must never be accessed from several threads or interrupts at once
+///   (see the safety requirements of `mod storage`).
+///   `storage = path::to::Backend` stores it in a type of your own implementing
+///   the generated `storage::LocaleStorage` trait, which should keep the locale per
+///   thread if `instrument` is used. A single lowercase name must be
+///   one of the modes above, so a backend type named in lowercase needs a path such
+///   as `self::backend`.
+///   `storage = [ui, logs]` generates an independent storage module for each
+///   name, such as `storage::ui` and `storage::logs`.
+/// * `env` — whether to generate detection of the locale from POSIX environment
+///   variables. Requires `std`. Default to `false`.
+/// * `persist` — whether to generate saving and loading of the current locale
+///   from a file in storage. Requires `std`. Default to `false`.
+/// * `test_support` — whether to generate `storage::test_guard` and to store the
+///   current locale of `storage = true` per thread under `cfg(test)`, so that
+///   parallel tests do not observe each other. Requires `std`. Default to `false`.
+///   The per-thread storage depends on `cfg(test)` of the crate where `init_locale!`
+///   is invoked, so a library tested from its `tests/` directory keeps the global
+///   storage there; `test_guard` still serializes such tests with each other.
 /// * `path` — path to the module where the macro is invoked. Used for resolving
 ///   paths in generated code. It is recommended to always specify this,
 ///   otherwise required imports for generated items may need to be added manually.
@@ -27,23 +81,34 @@ use crate::backends::expressions_from_files_backend;
 /// * `derive` — a list of additional derives applied to the generated `enum Locale`.
 ///   Defaults to an empty list.
 /// * `locale_name` — specifies a custom name for the `enum Locale`.
+/// * `fallback` — a map of fallback locales, such as `{ EnGb => En, Uk => Ru }`.
+///   When specified, expressions may omit locales: the value is taken from the
+///   fallback chain at compile time, which always ends with the default locale.
+///   Use `fallback = {}` to fall back to the default locale only.
 ///
 /// # Example with all features
 ///
 /// ```rust
 /// init_locale!(
-///     En => "English",
-///     Ru => "Russian",
+///     En(tag = "en-US") = 1 => "English",
+///     Ru(tag = "ru-RU") = 2 => "Russian",
 ///     storage = true,
+///     env = true,
+///     persist = true,
+///     test_support = true,
 ///     path = crate::locale,
 ///     default = En,
 ///     derive = [Deserialize, Serialize],
 ///     locale_name = Language,
+///     fallback = {},
 /// );
 /// ```
 ///
 /// # Generated
 ///
+/// Helper types are generated in submodules such as `plural` and `context`, so they
+/// don't clash with items of the module where `init_locale!` is called.
+///
 /// ### *enum Locale*
 ///
 /// *When `locale_name` is used, the enum name is replaced with the specified value*
@@ -59,6 +124,7 @@ use crate::backends::expressions_from_files_backend;
 ///     pub const COUNT: usize = /* number of locale variants */;
 ///     pub const VARIANTS: [Self; Self::COUNT] = /* array of locale variants */;
 ///     pub const LABELS: [&'static str; Self::COUNT] = /* array of locale variants labels */;
+///     pub const TAGS: [&'static str; Self::COUNT] = /* array of normalized locale variants tags */;
 ///     pub const DEFAULT: Self = /* compile-time equivalent of the `Default` trait */;
 ///
 ///     // Iterates over pairs of (variant, label)
@@ -70,7 +136,7 @@ use crate::backends::expressions_from_files_backend;
 ///     // Iterates over all labels
 ///     pub fn iter_labels() -> impl Iterator<Item=&'static str> { /* ... */ }
 ///
-///     // Converts the locale to `usize`
+///     // Converts the locale to its `usize` identifier
 ///     pub const fn to_usize(self) -> usize { /* ... */ }
 ///
 ///     // Converts from `usize`. Returns `None` if the value is invalid.
@@ -79,6 +145,12 @@ use crate::backends::expressions_from_files_backend;
 ///     // Converts from `usize`. Uses the default value if invalid.
 ///     pub fn from_usize_or_default(value: usize) -> Self { /* ... */ }
 ///
+///     // Converts the locale to its position in `VARIANTS`, used to index expressions
+///     pub const fn to_index(self) -> usize { /* ... */ }
+///
+///     // Converts from a position in `VARIANTS`. Returns `None` if out of range.
+///     pub const fn from_index(index: usize) -> Option<Self> { /* ... */ }
+///
 ///     // Converts the locale to `&str`
 ///     pub const fn to_str(self) -> &'static str { /* ... */ }
 ///
@@ -93,6 +165,44 @@ use crate::backends::expressions_from_files_backend;
 ///
 ///     // Converts from `&str`, ignoring ASCII case. Uses the default value if invalid.
 ///     pub fn from_caseless_str_or_default(str: &str) -> Self { /* ... */ }
+///
+///     // Converts the locale to its BCP 47 language tag
+///     pub const fn to_tag(self) -> &'static str { /* ... */ }
+///
+///     // Converts from a language tag such as `en-US`, `en_us` or `zh-Hant-TW`.
+///     // Language, script and region subtags are compared ignoring ASCII case,
+///     // other subtags are ignored. Returns `None` if invalid.
+///     pub fn from_tag(tag: &str) -> Option<Self> { /* ... */ }
+///
+///     // Converts from a language tag. Uses the default value if invalid.
+///     pub fn from_tag_or_default(tag: &str) -> Self { /* ... */ }
+///
+///     // Chooses the best locale for an RFC 9110 `Accept-Language` header,
+///     // respecting q-values and wildcards. A range falls back to a locale of
+///     // the same language (`en-GB` matches `en`). Returns `None` if nothing matches.
+///     pub fn try_negotiate(header: &str) -> Option<Self> { /* ... */ }
+///
+///     // Chooses the best locale for an `Accept-Language` header.
+///     // Uses the default value if nothing matches.
+///     pub fn negotiate(header: &str) -> Self { /* ... */ }
+///
+///     // Generated only if `env = true`.
+///     // Converts from a POSIX locale such as `ru_RU.UTF-8@euro` or a `:`-separated
+///     // priority list such as `en_US:ru`. Returns `None` if nothing matches.
+///     pub fn from_posix(value: &str) -> Option<Self> { /* ... */ }
+///
+///     // Generated only if `env = true`.
+///     // Reads the first non-empty of `LC_ALL`, `LC_MESSAGES`, `LANGUAGE` and `LANG`.
+///     // Uses the default value if it doesn't match, including `C` and `POSIX`.
+///     pub fn from_env() -> Self { /* ... */ }
+///
+///     // Generated only if `env = true`.
+///     // Converts the locale to a POSIX locale such as `en_US.UTF-8`
+///     pub const fn to_posix(self) -> &'static str { /* ... */ }
+///
+///     // Returns the CLDR cardinal plural category of a number for the locale language.
+///     // Accepts any primitive integer or float. Languages without known rules use `Other`.
+///     pub fn plural_category(self, number: impl Into<PluralOperands>) -> PluralCategory { /* ... */ }
 /// }
 ///
 /// impl Default for Locale { /* ... */ }
@@ -104,22 +214,95 @@ use crate::backends::expressions_from_files_backend;
 /// impl TryFrom<&str> for Locale { /* ... */ }
 /// ```
 ///
+/// ### *mod plural*
+///
+/// Types of `Locale::plural_category`.
+///
+/// ```rust
+/// pub enum PluralCategory { Zero, One, Two, Few, Many, Other }
+///
+/// // CLDR plural operands: absolute value, integer digits, number, value and
+/// // trailing-zero-free value of visible fraction digits. Integers beyond `u64`
+/// // keep their last 18 digits, which is enough for every CLDR rule.
+/// pub struct PluralOperands { pub n: f64, pub i: u64, pub v: u64, pub f: u64, pub t: u64 }
+/// ```
+///
+/// ### *mod context*
+///
+/// Types for passing the locale explicitly.
+///
+/// ```rust
+/// // Provides the locale for `localize!(EXPRESSION, in value)`.
+/// // Implemented for `Locale`, `LocaleContext` and references to implementors.
+/// pub trait CurrentLocale {
+///     fn current_locale(&self) -> Locale;
+/// }
+///
+/// // A context carrying the locale explicitly, without global storage
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// pub struct LocaleContext { pub locale: Locale }
+///
+/// impl LocaleContext {
+///     pub const fn new(locale: Locale) -> Self { /* ... */ }
+/// }
+/// ```
+///
 /// ### *mod storage*
 ///
-/// Generated only if `storage = true`.
+/// Generated only if `storage` is enabled.
 ///
 /// Stores the default locale upon initialization.
 ///
 /// The current locale is stored as an `AtomicUsize` using `Relaxed` ordering.
 ///
+/// With `storage = thread_local`, each thread stores its own current locale,
+/// which starts from a global default shared by all threads.
+///
+/// #### Safety
+///
+/// `storage = unsafe_single_core` shares the current locale, the generation and
+/// the listeners through `Cell`s without synchronization. Choosing it is a promise
+/// that the program runs on a single core and that no thread or interrupt preempts
+/// a storage function to call another one. Otherwise the behavior is undefined.
+///
+/// On targets without atomic compare-and-swap, `set`, `replace`, `generation` and
+/// `subscribe` fall back to separate atomic loads and stores, so concurrent changes
+/// from several threads or interrupts may lose a generation step or a listener.
+///
 /// ```rust
 /// pub mod storage {
+///     // Generated only if `storage = path::to::Backend`.
+///     // Implemented by the backend, which stores the locale as `usize`
+///     // and defines its initial value.
+///     pub trait LocaleStorage {
+///         fn load() -> usize;
+///         fn store(value: usize);
+///         // Stores the value and returns the previous one.
+///         // Defaults to `load` followed by `store`.
+///         fn swap(value: usize) -> usize { /* ... */ }
+///     }
+///
+///     // Maximum number of listeners registered with `subscribe`
+///     pub const MAX_LISTENERS: usize = 8;
+///
 ///     // Returns the current locale
 ///     pub fn get() -> Locale { /* ... */ }
 ///
 ///     // Sets the current locale
 ///     pub fn set(locale: Locale) { /* ... */ }
 ///
+///     // Sets the current locale and returns the previous one
+///     pub fn replace(locale: Locale) -> Locale { /* ... */ }
+///
+///     // Returns a counter incremented when `set`, `replace` or `reset` changes
+///     // the current locale
+///     pub fn generation() -> usize { /* ... */ }
+///
+///     // Registers a listener called with the old and the new locale when
+///     // `set`, `replace` or `reset` changes the current locale.
+///     // At most `MAX_LISTENERS` listeners can be registered.
+///     pub fn subscribe(listener: fn(Locale, Locale)) -> Result<(), &'static str> { /* ... */ }
+///
 ///     // Returns the current locale as `usize`
 ///     pub fn get_as_usize() -> usize { /* ... */ }
 ///
@@ -147,8 +330,68 @@ use crate::backends::expressions_from_files_backend;
 ///     // If the value is invalid, the locale falls back to the default.
 ///     pub fn set_from_caseless_str_or_default(str: &str) { /* ... */ }
 ///
+///     // Sets the current locale from a language tag.
+///     pub fn set_from_tag(tag: &str) -> Result<(), &'static str> { /* ... */ }
+///
+///     // Sets the current locale from a language tag.
+///     // If the value is invalid, the locale falls back to the default.
+///     pub fn set_from_tag_or_default(tag: &str) { /* ... */ }
+///
+///     // Sets the current locale from an `Accept-Language` header.
+///     pub fn set_from_accept_language(header: &str) -> Result<(), &'static str> { /* ... */ }
+///
+///     // Sets the current locale from an `Accept-Language` header.
+///     // If nothing matches, the locale falls back to the default.
+///     pub fn set_from_accept_language_or_default(header: &str) { /* ... */ }
+///
+///     // Generated only if `env = true`.
+///     // Sets the current locale from POSIX environment variables.
+///     pub fn init_from_env() { /* ... */ }
+///
+///     // Generated only if `persist = true`.
+///     // Sets the current locale from the language tag stored in the file.
+///     // If the file is missing or invalid, the locale falls back to the default.
+///     pub fn load_from(path: impl AsRef<Path>) { /* ... */ }
+///
+///     // Generated only if `persist = true`.
+///     // Writes the language tag of the current locale to the file,
+///     // replacing it atomically through a temporary `.tmp` file.
+///     pub fn save_to(path: impl AsRef<Path>) -> io::Result<()> { /* ... */ }
+///
 ///     // Resets the current locale to the default
 ///     pub fn reset() { /* ... */ }
+///
+///     // Sets the current locale until the guard is dropped,
+///     // then restores the previous one. The generation is not advanced and
+///     // listeners are not notified.
+///     pub fn scoped(locale: Locale) -> Guard { /* ... */ }
+///
+///     // Runs the function with the locale and restores the previous one
+///     // after it returns or panics
+///     pub fn with<T>(locale: Locale, function: impl FnOnce() -> T) -> T { /* ... */ }
+///
+///     // Generated only if `test_support = true`.
+///     // Waits until no other test guard is alive, then sets the current locale
+///     // until the guard is dropped. Listeners are not notified.
+///     pub fn test_guard(locale: Locale) -> TestGuard { /* ... */ }
+///
+///     // Generated only if `storage = thread_local` or a custom backend.
+///     // Wraps the future so that the locale is current while it is polled,
+///     // wherever it is polled. Changes of the locale inside the future are kept
+///     // for its next poll, and the previous locale is restored after each poll.
+///     pub fn instrument<F: Future>(future: F, locale: Locale) -> WithLocale<F> { /* ... */ }
+///
+///     // Generated only if `storage = thread_local`.
+///     // Returns the default locale of new threads.
+///     pub fn get_default() -> Locale { /* ... */ }
+///
+///     // Generated only if `storage = thread_local`.
+///     // Sets the default locale of new threads and of `reset`.
+///     pub fn set_default(locale: Locale) { /* ... */ }
+///
+///     // Generated only if `storage = thread_local`.
+///     // Spawns a thread that starts with the current locale of this thread.
+///     pub fn spawn_with_current<F, T>(function: F) -> JoinHandle<T> { /* ... */ }
 /// }
 /// ```
 ///
@@ -175,6 +418,63 @@ use crate::backends::expressions_from_files_backend;
 /// );
 /// ```
 ///
+/// Several locales can share one value using `|`, and the wildcard `_` fills
+/// every locale that is not listed.
+///
+/// ```rust
+/// expression!(
+///     IS_ENGLISH: bool => {
+///         En | EnGb: true,
+///         _: false,
+///     },
+/// );
+/// ```
+///
+/// If `fallback` is specified, locales can be omitted.
+///
+/// ```rust
+/// // init_locale!(En, EnGb, Ru, fallback = { EnGb => En });
+/// expression!(COLOR => {En: "Color", Ru: "Цвет"}); // `EnGb` is "Color"
+/// ```
+///
+/// The `plural` kind selects a template by the CLDR cardinal plural category of the locale
+/// language, taken from its tag. Each locale must provide exactly the categories its language
+/// uses, which is checked at compile time. `{n}` is replaced with the number, `{{` and `}}`
+/// escape braces. The number type is `u64` unless specified, such as `plural(f64)`.
+///
+/// ```rust
+/// expression!(
+///     ITEMS: plural => {
+///         En: {one: "{n} item", other: "{n} items"},
+///         Ru: {one: "{n} предмет", few: "{n} предмета", many: "{n} предметов", other: "{n} предмета"},
+///     },
+/// );
+/// ```
+///
+/// Each element is a `fn(u64) -> impl Display` that formats without allocation.
+///
+/// ```rust
+/// localize!(ITEMS => [21]).to_string(); // "21 предмет" for `Ru`
+/// ```
+///
+/// The `fmt` kind declares named placeholders that are formatted without allocation.
+/// A template may only use declared placeholders, `{{` and `}}` escape braces.
+///
+/// ```rust
+/// expression!(
+///     HELLO_WITH_NAME: fmt(name: &str) => {
+///         En: "Hello, {name}!",
+///         Ru: "Привет, {name}!",
+///     },
+/// );
+/// ```
+///
+/// Besides the static, a struct with the same name and one field per placeholder is generated.
+///
+/// Every locale of a `fmt` or `plural` expression must use the same set of placeholders.
+/// A template that drops a placeholder used by other locales or uses an unknown one
+/// is reported at its literal.
+///
 /// ### *expressions!*
 ///
 /// *`es!` with the `short_names` feature*
@@ -213,6 +513,21 @@ use crate::backends::expressions_from_files_backend;
 /// localize!(HELLO, Locale::En);
 /// ```
 ///
+/// The locale can also be taken from any value implementing `context::CurrentLocale`,
+/// such as a `context::LocaleContext` or a request struct of your own.
+///
+/// ```rust
+/// let context = context::LocaleContext::new(Locale::En);
+/// localize!(HELLO, in context);
+/// ```
+///
+/// With named storages such as `storage = [ui, logs]`, the first one is used
+/// by default, and another one can be selected with `@`.
+///
+/// ```rust
+/// localize!(HELLO, @logs);
+/// ```
+///
 /// If the expression is callable, it can be invoked with arguments.
 ///
 /// ```rust
@@ -220,6 +535,13 @@ use crate::backends::expressions_from_files_backend;
 /// localize!(HELLO_WITH_NAME => [name]);
 /// ```
 ///
+/// `fmt` expressions take named arguments in any order and return `impl Display`.
+///
+/// ```rust
+/// localize!(HELLO_WITH_NAME => {name: "Ivan"}).to_string();
+/// localize!(HELLO_WITH_NAME => {name: "Ivan"}, Locale::En);
+/// ```
+///
 /// # Generated with the `from_files` feature
 ///
 /// ### *expression_part!*
@@ -288,6 +610,264 @@ use crate::backends::expressions_from_files_backend;
 /// pub static HELLO: &'static str = "Привет";
 /// pub static IS_ENGLISH: bool = false;
 /// ```
+///
+/// # Generated with the `from_ftl` feature
+///
+/// ### *expressions_from_ftl!*
+///
+/// *`es_ftl!` with the `short_names` feature*
+///
+/// A macro for defining expressions from [Fluent](https://projectfluent.org) files, one per
+/// locale. `{locale}` in the path is replaced with the locale tag, and the path is relative
+/// to the crate root. Files are parsed at compile time and tracked for recompilation.
+///
+/// ```rust
+/// expressions_from_ftl!("locales/{locale}/main.ftl");
+/// ```
+///
+/// ```ftl
+/// # locales/en/main.ftl
+/// -brand = Localize It
+/// hello = Hello from { -brand }!
+/// welcome = Welcome, { $user-name }!
+/// items = { $count ->
+///     [one] { $count } item
+///    *[other] { $count } items
+/// }
+/// login = Log in
+///     .title = Log in to { -brand }
+/// ```
+///
+/// Each message becomes a static named in `CONSTANT_CASE`, attributes are appended to the
+/// message name (`LOGIN_TITLE`). Terms, message references and selections on literals are
+/// resolved at compile time. A message with variables becomes a `fmt` expression with `&str`
+/// placeholders (`$user-name` is `user_name`), a selection on a variable with plural category
+/// keys becomes a `plural` expression. Categories missing for a locale language are taken
+/// from the default variant. A variable named after a Rust keyword is passed as a raw
+/// identifier (`$type` is `r#type`).
+///
+/// ```rust
+/// localize!(WELCOME => {user_name: "Ivan"});
+/// localize!(ITEMS => [5]);
+/// ```
+///
+/// A syntax error, an unsupported construct such as a function call or a numeric variant key,
+/// a key missing in a locale without a fallback, or a message using different variables
+/// across locales is reported as a compile error with the file and line.
+///
+/// # Generated with the `from_po` feature
+///
+/// ### *expressions_from_po!*
+///
+/// *`es_po!` with the `short_names` feature*
+///
+/// A macro for defining expressions from gettext PO files, one per locale. `{locale}` in the
+/// path is replaced with the locale tag, and the path is relative to the crate root.
+///
+/// ```rust
+/// expressions_from_po!("locales/{locale}.po");
+/// ```
+///
+/// ```po
+/// # locales/ru.po
+/// msgid ""
+/// msgstr ""
+/// "Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : "
+/// "n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+///
+/// msgctxt "menu.OPEN"
+/// msgid "Open"
+/// msgstr "Открыть"
+///
+/// msgid "Welcome, {name}!"
+/// msgstr "Добро пожаловать, {name}!"
+///
+/// msgctxt "ITEMS"
+/// msgid "{count} item"
+/// msgid_plural "{count} items"
+/// msgstr[0] "{count} предмет"
+/// msgstr[1] "{count} предмета"
+/// msgstr[2] "{count} предметов"
+/// ```
+///
+/// `msgctxt` is the expression name, where `.` separates nested modules (`menu::OPEN`).
+/// Without it, the name is derived from `msgid` (`WELCOME_NAME`). Placeholders use the
+/// `{name}` syntax. Translated plural entries require the `Plural-Forms` header: each CLDR
+/// plural category of the locale language takes the `msgstr[i]` that the header formula
+/// selects for its integer samples, and categories without integer samples take the last
+/// form. A missing header, or a formula that selects different forms within one category,
+/// is reported as a compile error.
+///
+/// Fuzzy and untranslated entries are treated as missing, except in the default locale,
+/// which uses `msgid` and `msgid_plural`. A missing entry is taken from the fallback chain
+/// or reported as a compile error.
+///
+/// ### *export_pot!*
+///
+/// *`ex_pot!` with the `short_names` feature*
+///
+/// A macro for writing a POT template of the expressions into a `core::fmt::Write`.
+/// Supports `&str` expressions with literal values, `fmt` and `plural` expressions.
+/// Without a list, every supported expression of this `init_locale!` is written, as
+/// `expression!` registers it in a linker section. The registry is available on Linux,
+/// Android, the BSDs, Fuchsia, Apple targets and Windows, other targets need the list.
+/// Expressions are written in the order of their keys.
+/// `msgctxt` is the path of the module where the expression is defined, relative to the
+/// module of `init_locale!` and joined with `.`, so it doesn't depend on how the expression
+/// is named in the list (`HELLO`, `locale::HELLO` and `crate::locale::HELLO` give `HELLO`).
+/// An expression defined outside that module gets its path from the crate root. The result
+/// can be translated and imported back with `expressions_from_po!`. Doc comments of
+/// expressions are written as `#.` translator notes.
+///
+/// ```rust
+/// let mut pot = String::new();
+/// export_pot!(&mut pot)?;
+/// export_pot!(&mut pot, [HELLO, HELLO_WITH_NAME, menu::OPEN])?;
+/// ```
+///
+/// # Generated with the `from_json` feature
+///
+/// ### *expressions_from_json!*
+///
+/// *`es_json!` with the `short_names` feature*
+///
+/// A macro for defining expressions from JSON files, one per locale. `{locale}` in the path
+/// is replaced with the locale tag, and the path is relative to the crate root.
+///
+/// ```rust
+/// expressions_from_json!("locales/{locale}.json");
+/// ```
+///
+/// ```json
+/// {
+///     "hello": "Hello",
+///     "menu": { "file": { "open": "Open {file}" } },
+///     "items": { "one": "{count} item", "other": "{count} items" }
+/// }
+/// ```
+///
+/// Nested objects become nested modules (`menu::file::OPEN`), strings become templates with
+/// `{name}` placeholders. A key that is a Rust keyword gets a trailing underscore as a module
+/// (`menu::type_`), and a placeholder that is a keyword is a raw identifier (`r#type`).
+/// An object with plural category keys including `other` becomes a `plural` expression. A value of another type, a key that is a string in one locale and an
+/// object in another, or a missing key is reported as a compile error with the file and line.
+///
+/// ### *export_json!*
+///
+/// *`ex_json!` with the `short_names` feature*
+///
+/// A macro for writing the expressions of a locale into a `core::fmt::Write` as a JSON
+/// bundle that `expressions_from_json!` can import back. The key of an expression is
+/// built as in `export_pot!` and its segments become nested objects. Supports the same
+/// expressions and the same optional list as `export_pot!`.
+///
+/// ```rust
+/// let mut json = String::new();
+/// export_json!(&mut json, Locale::Ru)?;
+/// export_json!(&mut json, Locale::Ru, [HELLO, menu::file::OPEN])?;
+/// ```
+///
+/// # Generated with the `from_csv` feature
+///
+/// ### *expressions_from_csv!*
+///
+/// *`es_csv!` with the `short_names` feature*
+///
+/// A macro for defining expressions from a single CSV file with one row per key and one
+/// column per locale. The path is relative to the crate root.
+///
+/// ```rust
+/// expressions_from_csv!("locales/translations.csv");
+/// ```
+///
+/// ```csv
+/// key,notes,en,ru
+/// hello,Greeting on the start screen,Hello,Привет
+/// menu.open,,Open {file},Открыть {file}
+/// items[one],Number of items,{count} item,{count} предмет
+/// items[few],,,{count} предмета
+/// items[many],,,{count} предметов
+/// items[other],,{count} items,{count} предмета
+/// ```
+///
+/// The header row names the `key` and the optional `notes` columns, other columns are matched
+/// to locale variants by name or tag, case-insensitively. A column without a matching variant
+/// and a variant without a column are reported as compile errors. Keys separate nested
+/// modules with `.` (`menu::OPEN`), rows with `key[category]` form a `plural` expression.
+/// An empty cell is a missing translation, taken from the fallback chain or reported as a
+/// compile error. Notes become doc comments of the generated expressions.
+///
+/// ### *export_csv!*
+///
+/// *`ex_csv!` with the `short_names` feature*
+///
+/// A macro for writing the expressions of all locales into a `core::fmt::Write` in the
+/// layout `expressions_from_csv!` imports, with doc comments as notes. Columns are named by
+/// locale tags. Supports the same expressions, the same optional list and builds the same
+/// keys as `export_pot!`.
+///
+/// ```rust
+/// let mut csv = String::new();
+/// export_csv!(&mut csv)?;
+/// export_csv!(&mut csv, [HELLO, menu::OPEN, ITEMS])?;
+/// ```
+///
+/// # Generated with the `from_xliff` feature
+///
+/// ### *expressions_from_xliff!*
+///
+/// *`es_xliff!` with the `short_names` feature*
+///
+/// A macro for defining expressions from XLIFF 2.0 files, one per locale besides the default.
+/// `{locale}` in the path is replaced with the locale tag, and the path is relative to the
+/// crate root. `<source>` is the text of the default locale, `<target>` of the file locale.
+///
+/// ```rust
+/// expressions_from_xliff!("locales/{locale}.xlf");
+/// ```
+///
+/// ```xml
+/// <xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="ru">
+///   <file id="messages">
+///     <unit id="menu.OPEN">
+///       <notes><note>Shown in the file menu</note></notes>
+///       <segment state="reviewed">
+///         <source>Open <ph id="file" disp="{file}"/></source>
+///         <target>Открыть <ph id="file" disp="{file}"/></target>
+///       </segment>
+///     </unit>
+///     <group id="ITEMS" type="localize_it:plural">
+///       <unit id="ITEMS.one">...</unit>
+///       <unit id="ITEMS.other">...</unit>
+///     </group>
+///   </file>
+/// </xliff>
+/// ```
+///
+/// Unit ids separate nested modules with `.`, placeholders are `<ph>` elements with `{name}`
+/// in `disp` or `equiv`, and a group of type `localize_it:plural` forms a `plural` expression
+/// with one unit per category. `srcLang` and `trgLang` must match the locale tags. A unit
+/// without a `<target>` is a missing translation, taken from the fallback chain or reported
+/// as a compile error, and a source that differs between files is reported as well.
+///
+/// Notes become doc comments and segment states become `#[state(Ru = "reviewed")]`
+/// attributes of the generated expressions, which can also be written by hand. A plural
+/// group keeps the lowest state of its units.
+///
+/// ### *export_xliff!*
+///
+/// *`ex_xliff!` with the `short_names` feature*
+///
+/// A macro for writing the expressions into a `core::fmt::Write` as an XLIFF 2.0 file
+/// from `Locale::DEFAULT` to the given locale, with doc comments as notes and the states from
+/// `#[state]`. Supports the same expressions and the same optional list, and builds the same
+/// unit ids as `export_pot!` builds keys.
+///
+/// ```rust
+/// let mut xliff = String::new();
+/// export_xliff!(&mut xliff, Locale::Ru)?;
+/// export_xliff!(&mut xliff, Locale::Ru, [HELLO, menu::OPEN, ITEMS])?;
+/// ```
 #[proc_macro]
 pub fn init_locale(input: TokenStream) -> TokenStream {
     run_backend(init_locale_backend, input)
@@ -305,3 +885,50 @@ pub fn __expression(input: TokenStream) -> TokenStream {
 pub fn __expressions_from_files(input: TokenStream) -> TokenStream {
     run_backend(expressions_from_files_backend, input)
 }
+
+#[cfg(feature = "from_ftl")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expressions_from_ftl(input: TokenStream) -> TokenStream {
+    run_backend(expressions_from_ftl_backend, input)
+}
+
+#[cfg(feature = "from_po")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expressions_from_po(input: TokenStream) -> TokenStream {
+    run_backend(expressions_from_po_backend, input)
+}
+
+#[cfg(feature = "from_json")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expressions_from_json(input: TokenStream) -> TokenStream {
+    run_backend(expressions_from_json_backend, input)
+}
+
+#[cfg(feature = "from_csv")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expressions_from_csv(input: TokenStream) -> TokenStream {
+    run_backend(expressions_from_csv_backend, input)
+}
+
+#[cfg(feature = "from_xliff")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expressions_from_xliff(input: TokenStream) -> TokenStream {
+    run_backend(expressions_from_xliff_backend, input)
+}
+
+#[cfg(any(
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __export(input: TokenStream) -> TokenStream {
+    run_backend(export_backend, input)
+}
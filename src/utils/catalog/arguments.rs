@@ -0,0 +1,67 @@
+use crate::utils::{
+    aliases::SynResult,
+    errors::{RequiredArgumentError, UnknownArgumentError},
+    typed_parse::TypedParse,
+};
+use proc_macro2::Ident;
+use syn::{
+    LitStr, Path, Token,
+    parse::{Parse, ParseStream},
+};
+
+pub struct CatalogArguments {
+    pub file: LitStr,
+    pub default: Ident,
+    pub variants: Vec<Ident>,
+    pub tags: Vec<String>,
+    pub fallbacks: Option<Vec<Ident>>,
+    pub path: Option<Path>,
+    pub locale_name: String,
+}
+
+impl Parse for CatalogArguments {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut file = None;
+        let mut default = None;
+        let mut variants = None;
+        let mut tags = None;
+        let mut fallbacks = None;
+        let mut path = None;
+        let mut locale_name = None;
+
+        input.parse_arguments(|argument, processor| {
+            input.parse::<Token![=]>()?;
+
+            match processor.process(&argument)?.as_str() {
+                "file" => file = Some(input.parse_lit_str("file")?),
+                "default" => default = Some(input.parse_ident("default")?),
+                "variants" => variants = Some(input.parse_array("variants", Ident::parse)?),
+                "tags" => {
+                    tags = Some(
+                        input
+                            .parse_array("tags", <LitStr as Parse>::parse)?
+                            .iter()
+                            .map(LitStr::value)
+                            .collect(),
+                    )
+                }
+                "fallbacks" => fallbacks = Some(input.parse_array("fallbacks", Ident::parse)?),
+                "path" => path = Some(input.parse_path("path")?),
+                "locale_name" => locale_name = Some(input.parse_string("locale_name")?),
+                _ => Err(UnknownArgumentError::new(argument))?,
+            };
+
+            Ok(())
+        })?;
+
+        Ok(Self {
+            file: file.ok_or(RequiredArgumentError::new("file"))?,
+            default: default.ok_or(RequiredArgumentError::new("default"))?,
+            variants: variants.ok_or(RequiredArgumentError::new("variants"))?,
+            tags: tags.ok_or(RequiredArgumentError::new("tags"))?,
+            fallbacks,
+            path,
+            locale_name: locale_name.ok_or(RequiredArgumentError::new("locale_name"))?,
+        })
+    }
+}
@@ -0,0 +1,536 @@
+mod arguments;
+mod resource;
+
+pub use arguments::CatalogArguments;
+pub use resource::Resource;
+
+use crate::utils::{
+    NamesProvider,
+    aliases::{SynError, SynResult},
+    combine_errors,
+    errors::{
+        MissingResourceKeyError, NoPluralRulesError, ResourceMessageError, ResourcePlaceholderError,
+    },
+    language_tag::LanguageTag,
+    path_argument,
+    plural_rules::PluralRules,
+    template::Piece,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+#[derive(Clone, PartialEq)]
+pub enum Translation {
+    Text(Vec<Piece>),
+    Plural {
+        selector: String,
+        categories: Vec<(String, Vec<Piece>)>,
+        default: usize,
+    },
+}
+
+struct Message {
+    key: Vec<String>,
+    note: Option<String>,
+    states: Vec<Option<String>>,
+    translations: Vec<Option<Translation>>,
+}
+
+#[derive(Default)]
+struct Module {
+    name: String,
+    definitions: Vec<TokenStream>,
+    children: Vec<Module>,
+}
+
+pub struct Catalog<'a> {
+    arguments: &'a CatalogArguments,
+    sources: Vec<&'a Resource>,
+    messages: Vec<Message>,
+}
+
+impl<'a> Catalog<'a> {
+    pub fn new(arguments: &'a CatalogArguments, sources: Vec<&'a Resource>) -> Self {
+        Self {
+            arguments,
+            sources,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn insert(
+        &mut self,
+        variant: usize,
+        key: Vec<String>,
+        translation: Translation,
+    ) -> Option<Translation> {
+        self.message(key).translations[variant].replace(translation)
+    }
+
+    #[cfg(any(feature = "from_po", feature = "from_csv", feature = "from_xliff"))]
+    pub fn note(&mut self, key: Vec<String>, note: String) {
+        self.message(key).note.get_or_insert(note);
+    }
+
+    #[cfg(feature = "from_xliff")]
+    pub fn state(&mut self, variant: usize, key: Vec<String>, state: String) {
+        self.message(key).states[variant] = Some(state);
+    }
+
+    fn message(&mut self, key: Vec<String>) -> &mut Message {
+        let count = self.arguments.variants.len();
+        let index = match self.messages.iter().position(|message| message.key == key) {
+            Some(index) => index,
+            None => {
+                self.messages.push(Message {
+                    key,
+                    note: None,
+                    states: vec![None; count],
+                    translations: (0..count).map(|_| None).collect(),
+                });
+
+                self.messages.len() - 1
+            }
+        };
+
+        &mut self.messages[index]
+    }
+
+    pub fn expand(&self, names_provider: &NamesProvider) -> SynResult<TokenStream> {
+        let localize_it_crate = names_provider.get_crate_name("localize_it")?;
+        let arguments = self.arguments;
+        let variants = &arguments.variants;
+        let tags = &arguments.tags;
+        let fallbacks = arguments.fallbacks.as_ref().map(|fallbacks| {
+            quote! {
+                fallbacks = [#(#fallbacks),*],
+            }
+        });
+        let path_argument = path_argument(arguments.path.clone());
+        let locale_name = &arguments.locale_name;
+
+        let mut errors = Vec::new();
+        let mut root = Module::default();
+        let mut defined = Vec::<(Vec<String>, String, String)>::new();
+        for message in &self.messages {
+            errors.extend(self.check(message));
+
+            let key = message.key.join(".");
+            let (name, modules) = message
+                .key
+                .split_last()
+                .map(|(name, modules)| (constant_name(name), modules))
+                .unwrap_or_default();
+            let converted = modules.iter().map(|module| module_name(module)).collect();
+            if let Some((.., other)) = defined
+                .iter()
+                .find(|(modules, defined_name, _)| *modules == converted && *defined_name == name)
+            {
+                let variant = message
+                    .translations
+                    .iter()
+                    .position(Option::is_some)
+                    .unwrap_or_default();
+
+                errors.push(ResourceMessageError::new(
+                    &arguments.file,
+                    &self.sources[variant].path,
+                    &key,
+                    format!("converts to the same name `{name}` as `{other}`"),
+                ));
+                continue;
+            }
+            defined.push((converted, name.clone(), key));
+            match self.definition(message, &name) {
+                Ok(definition) => root.get(modules).definitions.push(definition),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if let Some(error) = combine_errors(errors) {
+            Err(error)?;
+        }
+
+        let mut tracked = self
+            .sources
+            .iter()
+            .map(|source| source.absolute.as_str())
+            .collect::<Vec<_>>();
+        tracked.dedup();
+
+        let expression = |definitions: &[TokenStream]| {
+            quote! {
+                #localize_it_crate::__expression!(
+                    expressions = [#(#definitions),*],
+                    variants = [#(#variants),*],
+                    tags = [#(#tags),*],
+                    #fallbacks
+                    #path_argument
+                    locale_name = #locale_name,
+                );
+            }
+        };
+        let modules = root.expand(&expression);
+
+        Ok(quote! {
+            #(const _: &[u8] = include_bytes!(#tracked);)*
+
+            #modules
+        })
+    }
+
+    fn check(&self, message: &Message) -> Vec<SynError> {
+        let arguments = self.arguments;
+        let file = &arguments.file;
+        let key = message.key.join(".");
+        let variants = &arguments.variants;
+        let find_variant = |variant: &Ident| {
+            variants
+                .iter()
+                .position(|candidate| candidate == variant)
+                .unwrap_or_default()
+        };
+
+        let mut errors = Vec::new();
+        for (i, translation) in message.translations.iter().enumerate() {
+            if translation.is_some() {
+                continue;
+            }
+
+            let mut current = i;
+            let covered = loop {
+                match &arguments.fallbacks {
+                    Some(fallbacks) if find_variant(&fallbacks[current]) != current => {
+                        current = find_variant(&fallbacks[current]);
+
+                        if message.translations[current].is_some() {
+                            break true;
+                        }
+                    }
+                    _ => break false,
+                }
+            };
+
+            if !covered {
+                errors.push(MissingResourceKeyError::new(
+                    file,
+                    &self.sources[i].path,
+                    &variants[i],
+                    &key,
+                ));
+            }
+        }
+
+        let default = find_variant(&arguments.default);
+        let Some(reference) =
+            message.translations[default]
+                .as_ref()
+                .or(message.translations.iter().flatten().next())
+        else {
+            return errors;
+        };
+        let reference = placeholders(reference);
+
+        for (i, translation) in message.translations.iter().enumerate() {
+            let Some(translation) = translation else {
+                continue;
+            };
+
+            let placeholders = placeholders(translation);
+            let missing = reference
+                .iter()
+                .filter(|placeholder| !placeholders.contains(placeholder))
+                .copied()
+                .collect::<Vec<_>>();
+            let unknown = placeholders
+                .iter()
+                .filter(|placeholder| !reference.contains(placeholder))
+                .copied()
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() || !unknown.is_empty() {
+                errors.push(ResourcePlaceholderError::new(
+                    file,
+                    &self.sources[i].path,
+                    &variants[i],
+                    &key,
+                    &missing,
+                    &unknown,
+                ));
+            }
+        }
+
+        errors
+    }
+
+    fn definition(&self, message: &Message, name: &str) -> SynResult<TokenStream> {
+        let arguments = self.arguments;
+        let name = Ident::new(name, Span::call_site());
+        let note = message
+            .note
+            .iter()
+            .flat_map(|note| note.lines())
+            .map(|line| format!(" {line}"))
+            .collect::<Vec<_>>();
+        let states = message
+            .states
+            .iter()
+            .zip(&arguments.variants)
+            .filter_map(|(state, variant)| state.as_ref().map(|state| quote!(#variant = #state)))
+            .collect::<Vec<_>>();
+        let states = (!states.is_empty()).then(|| quote!(#[state(#(#states),*)]));
+        let key = message.key.join(".");
+        let present = message
+            .translations
+            .iter()
+            .enumerate()
+            .filter_map(|(i, translation)| translation.as_ref().map(|translation| (i, translation)))
+            .collect::<Vec<_>>();
+        let variants = present
+            .iter()
+            .map(|(i, _)| &arguments.variants[*i])
+            .collect::<Vec<_>>();
+
+        let selector = present
+            .iter()
+            .find_map(|(_, translation)| match translation {
+                Translation::Plural { selector, .. } => Some(selector.as_str()),
+                Translation::Text(_) => None,
+            });
+
+        if let Some(selector) = selector {
+            let mut values = Vec::new();
+            for (i, translation) in &present {
+                let path = &self.sources[*i].path;
+                let error = |message: String| {
+                    ResourceMessageError::new(&arguments.file, path, &key, message)
+                };
+
+                if let Some(placeholder) = placeholders(translation)
+                    .into_iter()
+                    .find(|placeholder| *placeholder != selector)
+                {
+                    Err(error(format!(
+                        "plural messages can only use the selector `{selector}`, found `{placeholder}`"
+                    )))?;
+                }
+
+                let [language, ..] = LanguageTag::matching_subtags(&arguments.tags[*i]);
+                let rules = PluralRules::new(&language).ok_or_else(|| {
+                    NoPluralRulesError::new(&name, &arguments.variants[*i], &language)
+                })?;
+                let categories = rules.categories().into_iter().map(|category| {
+                    let pieces = match translation {
+                        Translation::Text(pieces) => pieces,
+                        Translation::Plural {
+                            categories,
+                            default,
+                            ..
+                        } => categories
+                            .iter()
+                            .find(|(name, _)| name == category)
+                            .map_or(&categories[*default].1, |(_, pieces)| pieces),
+                    };
+                    let category = Ident::new(category, Span::call_site());
+                    let template = template_string(&rename(pieces, selector, "n"));
+
+                    quote!(#category: #template)
+                });
+
+                values.push(quote!({ #(#categories),* }));
+            }
+
+            return Ok(quote! {
+                #(#[doc = #note])*
+                #states
+                #name: plural => { #(#variants: #values),* }
+            });
+        }
+
+        let parameters = present
+            .first()
+            .map(|(i, translation)| {
+                placeholders(translation)
+                    .into_iter()
+                    .map(|placeholder| {
+                        placeholder_ident(placeholder).ok_or_else(|| {
+                            ResourceMessageError::new(
+                                &arguments.file,
+                                &self.sources[*i].path,
+                                &key,
+                                format!(
+                                    "placeholder `{{{placeholder}}}` is not a valid identifier"
+                                ),
+                            )
+                        })
+                    })
+                    .collect::<SynResult<Vec<_>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let values = present.iter().map(|(_, translation)| match translation {
+            Translation::Text(pieces) if parameters.is_empty() => pieces
+                .iter()
+                .map(|piece| match piece {
+                    Piece::Text(text) => text.as_str(),
+                    Piece::Placeholder(_) => "",
+                })
+                .collect::<String>(),
+            Translation::Text(pieces) => template_string(pieces),
+            Translation::Plural { .. } => String::new(),
+        });
+
+        Ok(if parameters.is_empty() {
+            quote! {
+                #(#[doc = #note])*
+                #states
+                #name => { #(#variants: #values),* }
+            }
+        } else {
+            quote! {
+                #(#[doc = #note])*
+                #states
+                #name: fmt(#(#parameters: &str),*) => { #(#variants: #values),* }
+            }
+        })
+    }
+}
+
+impl Module {
+    fn get(&mut self, path: &[String]) -> &mut Self {
+        let Some((first, rest)) = path.split_first() else {
+            return self;
+        };
+        let name = module_name(first);
+
+        let index = match self.children.iter().position(|child| child.name == name) {
+            Some(index) => index,
+            None => {
+                self.children.push(Self {
+                    name,
+                    ..Self::default()
+                });
+
+                self.children.len() - 1
+            }
+        };
+
+        self.children[index].get(rest)
+    }
+
+    fn expand(&self, expression: &impl Fn(&[TokenStream]) -> TokenStream) -> TokenStream {
+        let definitions = if self.definitions.is_empty() {
+            TokenStream::new()
+        } else {
+            expression(&self.definitions)
+        };
+        let children = self.children.iter().map(|child| {
+            let name = Ident::new(&child.name, Span::call_site());
+            let content = child.expand(expression);
+
+            quote! {
+                pub mod #name {
+                    use super::*;
+
+                    #content
+                }
+            }
+        });
+
+        quote! {
+            #definitions
+            #(#children)*
+        }
+    }
+}
+
+fn placeholders(translation: &Translation) -> Vec<&str> {
+    let pieces = match translation {
+        Translation::Text(pieces) => vec![pieces],
+        Translation::Plural { categories, .. } => {
+            categories.iter().map(|(_, pieces)| pieces).collect()
+        }
+    };
+
+    let mut placeholders = Vec::new();
+    for piece in pieces.into_iter().flatten() {
+        if let Piece::Placeholder(placeholder) = piece
+            && !placeholders.contains(&placeholder.as_str())
+        {
+            placeholders.push(placeholder.as_str());
+        }
+    }
+
+    placeholders
+}
+
+fn rename(pieces: &[Piece], from: &str, to: &str) -> Vec<Piece> {
+    pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Placeholder(placeholder) if placeholder == from => {
+                Piece::Placeholder(to.to_string())
+            }
+            piece => piece.clone(),
+        })
+        .collect()
+}
+
+pub fn identifier(key: &str) -> String {
+    let mut identifier = String::new();
+    let mut previous = '_';
+    for char in key.chars() {
+        let char = if char.is_ascii_alphanumeric() {
+            char
+        } else {
+            '_'
+        };
+
+        if char.is_ascii_uppercase() && (previous.is_ascii_lowercase() || previous.is_ascii_digit())
+        {
+            identifier.push('_');
+        }
+        if char != '_' || previous != '_' {
+            identifier.push(char);
+        }
+
+        previous = char;
+    }
+
+    let identifier = identifier.trim_matches('_').to_ascii_lowercase();
+    if identifier.is_empty() || identifier.starts_with(|char: char| char.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else {
+        identifier
+    }
+}
+
+fn placeholder_ident(placeholder: &str) -> Option<Ident> {
+    syn::parse_str::<Ident>(placeholder)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{placeholder}")))
+        .ok()
+}
+
+fn constant_name(key: &str) -> String {
+    identifier(key).to_ascii_uppercase()
+}
+
+fn module_name(key: &str) -> String {
+    let name = identifier(key);
+
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        name
+    } else {
+        format!("{name}_")
+    }
+}
+
+fn template_string(pieces: &[Piece]) -> String {
+    pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => text.replace('{', "{{").replace('}', "}}"),
+            Piece::Placeholder(placeholder) => format!("{{{placeholder}}}"),
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use crate::utils::{
+    aliases::{SynError, SynResult},
+    errors::{ReadFileError, ResourceError},
+};
+use std::{env, fmt::Display, fs, path::PathBuf};
+use syn::LitStr;
+
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_xliff"
+))]
+use crate::utils::combine_errors;
+
+pub struct Resource {
+    pub path: String,
+    pub absolute: String,
+    pub content: String,
+}
+
+impl Resource {
+    pub fn read(file: &LitStr, path: String) -> SynResult<Self> {
+        let absolute = env::var("CARGO_MANIFEST_DIR")
+            .map(|directory| PathBuf::from(directory).join(&path))
+            .unwrap_or(PathBuf::from(&path));
+        let content = fs::read_to_string(&absolute)
+            .map_err(|error| ReadFileError::new(file, &path, &error))?;
+
+        Ok(Self {
+            path,
+            absolute: absolute.to_string_lossy().into_owned(),
+            content: content.replace("\r\n", "\n"),
+        })
+    }
+
+    #[cfg(any(
+        feature = "from_ftl",
+        feature = "from_po",
+        feature = "from_json",
+        feature = "from_xliff"
+    ))]
+    pub fn read_localized(file: &LitStr, tags: &[String]) -> SynResult<Vec<Self>> {
+        let mut resources = Vec::new();
+        let mut errors = Vec::new();
+        for tag in tags {
+            match Self::read(file, file.value().replace("{locale}", tag)) {
+                Ok(resource) => resources.push(resource),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        combine_errors(errors).map_or(Ok(resources), Err)
+    }
+
+    pub fn error(&self, file: &LitStr, line: usize, message: impl Display) -> SynError {
+        ResourceError::new(file, &self.path, line, message)
+    }
+}
@@ -0,0 +1,70 @@
+pub fn closest<'a, T: ToString>(
+    name: &impl ToString,
+    candidates: impl IntoIterator<Item = &'a T>,
+) -> Option<&'a T> {
+    let name = name.to_string().to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            (
+                distance(&name, &candidate.to_string().to_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn distance(left: &str, right: &str) -> usize {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+    let mut before_previous = Vec::new();
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+
+    for (i, left_char) in left.iter().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != right_char);
+
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+
+            if i > 0 && j > 0 && *left_char == right[j - 1] && left[i - 1] == *right_char {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+
+        before_previous = std::mem::replace(&mut previous, current);
+    }
+
+    previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, distance};
+
+    #[test]
+    fn distances() {
+        assert_eq!(distance("ru", "ru"), 0);
+        assert_eq!(distance("ru", "rus"), 1);
+        assert_eq!(distance("engb", "enbg"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "en"), 2);
+    }
+
+    #[test]
+    fn closest_candidate() {
+        let candidates = ["En", "EnGb", "Ru", "Uk"];
+
+        assert_eq!(closest(&"Rus", &candidates), Some(&"Ru"));
+        assert_eq!(closest(&"ENGB", &candidates), Some(&"EnGb"));
+        assert_eq!(closest(&"EnBg", &candidates), Some(&"EnGb"));
+        assert_eq!(closest(&"Fr", &candidates), None);
+        assert_eq!(closest(&"Deutsch", &candidates), None);
+        assert_eq!(closest(&"Ru", &[] as &[&str]), None);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct DuplicateLocaleVariantError;
+
+impl DuplicateLocaleVariantError {
+    pub fn new(variant: &Ident) -> SynError {
+        SynError::new(
+            variant.span(),
+            format!("Locale variant `{variant}` is duplicated"),
+        )
+    }
+}
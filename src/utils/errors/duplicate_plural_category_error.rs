@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct DuplicatePluralCategoryError;
+
+impl DuplicatePluralCategoryError {
+    pub fn new(category: &Ident) -> SynError {
+        SynError::new(
+            category.span(),
+            format!("Plural category `{category}` is duplicated"),
+        )
+    }
+}
@@ -0,0 +1,10 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct DuplicateStorageError;
+
+impl DuplicateStorageError {
+    pub fn new(name: &Ident) -> SynError {
+        SynError::new(name.span(), format!("Duplicate storage `{name}`"))
+    }
+}
@@ -0,0 +1,10 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Span;
+
+pub struct DuplicateTagError;
+
+impl DuplicateTagError {
+    pub fn new(span: Span, tag: &str) -> SynError {
+        SynError::new(span, format!("Duplicate language tag `{tag}`"))
+    }
+}
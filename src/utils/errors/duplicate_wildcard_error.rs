@@ -0,0 +1,10 @@
+use crate::utils::aliases::SynError;
+use syn::{Token, spanned::Spanned};
+
+pub struct DuplicateWildcardError;
+
+impl DuplicateWildcardError {
+    pub fn new(underscore: &Token![_]) -> SynError {
+        SynError::new(underscore.span(), "Wildcard arm `_` is duplicated")
+    }
+}
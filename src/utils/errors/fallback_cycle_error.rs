@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct FallbackCycleError;
+
+impl FallbackCycleError {
+    pub fn new(variant: &Ident) -> SynError {
+        SynError::new(
+            variant.span(),
+            format!("Fallback chain of `{variant}` never reaches the default locale"),
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use syn::LitStr;
+
+pub struct InvalidTagError;
+
+impl InvalidTagError {
+    pub fn new(tag: &LitStr) -> SynError {
+        SynError::new(
+            tag.span(),
+            format!("`{}` is not a valid BCP 47 language tag", tag.value()),
+        )
+    }
+}
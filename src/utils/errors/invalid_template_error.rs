@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use syn::LitStr;
+
+pub struct InvalidTemplateError;
+
+impl InvalidTemplateError {
+    pub fn new(template: &LitStr) -> SynError {
+        SynError::new(
+            template.span(),
+            "Invalid template: unmatched `{` or `}`, use `{{` and `}}` for literal braces",
+        )
+    }
+}
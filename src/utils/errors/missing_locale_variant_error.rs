@@ -0,0 +1,19 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct MissingLocaleVariantError;
+
+impl MissingLocaleVariantError {
+    pub fn new(name: &Ident, variants: &[&Ident]) -> SynError {
+        let variants = variants
+            .iter()
+            .map(|variant| format!("`{variant}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        SynError::new(
+            name.span(),
+            format!("Missing locale variants in `{name}`: {variants}"),
+        )
+    }
+}
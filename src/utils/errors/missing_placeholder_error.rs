@@ -0,0 +1,19 @@
+use crate::utils::aliases::SynError;
+use syn::LitStr;
+
+pub struct MissingPlaceholderError;
+
+impl MissingPlaceholderError {
+    pub fn new(template: &LitStr, placeholders: &[&str]) -> SynError {
+        let placeholders = placeholders
+            .iter()
+            .map(|placeholder| format!("`{{{placeholder}}}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        SynError::new(
+            template.span(),
+            format!("Missing placeholders used by other locales: {placeholders}"),
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::{Ident, Span};
+
+pub struct MissingPluralCategoryError;
+
+impl MissingPluralCategoryError {
+    pub fn new(span: Span, variant: &Ident, categories: &[&str]) -> SynError {
+        let categories = categories
+            .iter()
+            .map(|category| format!("`{category}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        SynError::new(
+            span,
+            format!("Missing plural categories for `{variant}`: {categories}"),
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+use syn::LitStr;
+
+pub struct MissingResourceKeyError;
+
+impl MissingResourceKeyError {
+    pub fn new(file: &LitStr, path: &str, variant: &Ident, key: &str) -> SynError {
+        SynError::new(
+            file.span(),
+            format!("Missing key `{key}` for `{variant}` in `{path}`"),
+        )
+    }
+}
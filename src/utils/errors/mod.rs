@@ -1,17 +1,135 @@
 mod crate_not_found_error;
 mod duplicate_argument_error;
+mod duplicate_locale_variant_error;
+mod duplicate_plural_category_error;
+mod duplicate_storage_error;
+mod duplicate_tag_error;
+mod duplicate_wildcard_error;
+mod fallback_cycle_error;
+mod invalid_tag_error;
+mod invalid_template_error;
 mod locale_variant_position_error;
+mod missing_locale_variant_error;
+mod missing_placeholder_error;
+mod missing_plural_category_error;
 mod no_comma_between_argument_error;
 mod no_locale_variant_error;
+mod no_plural_rules_error;
 mod required_argument_error;
 mod type_error;
+mod unexpected_plural_category_error;
 mod unknown_argument_error;
+mod unknown_locale_variant_error;
+mod unknown_placeholder_error;
+mod unknown_plural_category_error;
+mod unknown_segment_state_error;
+mod unknown_storage_error;
 
 pub use crate_not_found_error::CrateNotFoundError;
 pub use duplicate_argument_error::DuplicateArgumentError;
+pub use duplicate_locale_variant_error::DuplicateLocaleVariantError;
+pub use duplicate_plural_category_error::DuplicatePluralCategoryError;
+pub use duplicate_storage_error::DuplicateStorageError;
+pub use duplicate_tag_error::DuplicateTagError;
+pub use duplicate_wildcard_error::DuplicateWildcardError;
+pub use fallback_cycle_error::FallbackCycleError;
+pub use invalid_tag_error::InvalidTagError;
+pub use invalid_template_error::InvalidTemplateError;
 pub use locale_variant_position_error::LocaleVariantPositionError;
+pub use missing_locale_variant_error::MissingLocaleVariantError;
+pub use missing_placeholder_error::MissingPlaceholderError;
+pub use missing_plural_category_error::MissingPluralCategoryError;
 pub use no_comma_between_argument_error::NoCommaBetweenArgumentError;
 pub use no_locale_variant_error::NoLocaleVariantError;
+pub use no_plural_rules_error::NoPluralRulesError;
 pub use required_argument_error::RequiredArgumentError;
 pub use type_error::TypeError;
+pub use unexpected_plural_category_error::UnexpectedPluralCategoryError;
 pub use unknown_argument_error::UnknownArgumentError;
+pub use unknown_locale_variant_error::UnknownLocaleVariantError;
+pub use unknown_placeholder_error::UnknownPlaceholderError;
+pub use unknown_plural_category_error::UnknownPluralCategoryError;
+pub use unknown_segment_state_error::UnknownSegmentStateError;
+pub use unknown_storage_error::UnknownStorageError;
+
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod missing_resource_key_error;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod read_file_error;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod resource_error;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod resource_message_error;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+mod resource_placeholder_error;
+
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use missing_resource_key_error::MissingResourceKeyError;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use read_file_error::ReadFileError;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use resource_error::ResourceError;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use resource_message_error::ResourceMessageError;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub use resource_placeholder_error::ResourcePlaceholderError;
@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct NoPluralRulesError;
+
+impl NoPluralRulesError {
+    pub fn new(name: &Ident, variant: &Ident, language: &str) -> SynError {
+        SynError::new(
+            name.span(),
+            format!("No plural rules for language `{language}` of `{variant}` in `{name}`"),
+        )
+    }
+}
@@ -0,0 +1,11 @@
+use crate::utils::aliases::SynError;
+use std::io;
+use syn::LitStr;
+
+pub struct ReadFileError;
+
+impl ReadFileError {
+    pub fn new(file: &LitStr, path: &str, error: &io::Error) -> SynError {
+        SynError::new(file.span(), format!("Failed to read `{path}`: {error}"))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::utils::aliases::SynError;
+use std::fmt::Display;
+use syn::LitStr;
+
+pub struct ResourceError;
+
+impl ResourceError {
+    pub fn new(file: &LitStr, path: &str, line: usize, message: impl Display) -> SynError {
+        SynError::new(file.span(), format!("{path}:{line}: {message}"))
+    }
+}
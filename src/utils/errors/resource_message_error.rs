@@ -0,0 +1,11 @@
+use crate::utils::aliases::SynError;
+use std::fmt::Display;
+use syn::LitStr;
+
+pub struct ResourceMessageError;
+
+impl ResourceMessageError {
+    pub fn new(file: &LitStr, path: &str, key: &str, message: impl Display) -> SynError {
+        SynError::new(file.span(), format!("`{key}` in `{path}`: {message}"))
+    }
+}
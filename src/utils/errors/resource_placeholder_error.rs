@@ -0,0 +1,39 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+use syn::LitStr;
+
+pub struct ResourcePlaceholderError;
+
+impl ResourcePlaceholderError {
+    pub fn new(
+        file: &LitStr,
+        path: &str,
+        variant: &Ident,
+        key: &str,
+        missing: &[&str],
+        unknown: &[&str],
+    ) -> SynError {
+        let list = |placeholders: &[&str]| {
+            placeholders
+                .iter()
+                .map(|placeholder| format!("`{{{placeholder}}}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let problems = [
+            (!missing.is_empty()).then(|| format!("missing {}", list(missing))),
+            (!unknown.is_empty()).then(|| format!("unknown {}", list(unknown))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+
+        SynError::new(
+            file.span(),
+            format!(
+                "Placeholders of `{key}` for `{variant}` in `{path}` differ from the default locale: {problems}"
+            ),
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct UnexpectedPluralCategoryError;
+
+impl UnexpectedPluralCategoryError {
+    pub fn new(category: &Ident, variant: &Ident) -> SynError {
+        SynError::new(
+            category.span(),
+            format!("Plural category `{category}` is not used by `{variant}`"),
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use crate::utils::{aliases::SynError, closest};
+use proc_macro2::Ident;
+
+pub struct UnknownLocaleVariantError;
+
+impl UnknownLocaleVariantError {
+    pub fn new(variant: &Ident, variants: &[Ident]) -> SynError {
+        let suggestion = closest(variant, variants).map_or(String::new(), |closest| {
+            format!(", did you mean `{closest}`?")
+        });
+
+        SynError::new(
+            variant.span(),
+            format!("Unknown locale variant `{variant}`{suggestion}"),
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use crate::utils::{aliases::SynError, closest};
+use proc_macro2::Ident;
+use syn::LitStr;
+
+pub struct UnknownPlaceholderError;
+
+impl UnknownPlaceholderError {
+    pub fn new(template: &LitStr, placeholder: &str, placeholders: &[Ident]) -> SynError {
+        let suggestion = closest(&placeholder, placeholders).map_or(String::new(), |closest| {
+            format!(", did you mean `{{{closest}}}`?")
+        });
+
+        SynError::new(
+            template.span(),
+            format!("Unknown placeholder `{{{placeholder}}}`{suggestion}"),
+        )
+    }
+}
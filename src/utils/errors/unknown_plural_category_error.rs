@@ -0,0 +1,15 @@
+use crate::utils::aliases::SynError;
+use proc_macro2::Ident;
+
+pub struct UnknownPluralCategoryError;
+
+impl UnknownPluralCategoryError {
+    pub fn new(category: &Ident) -> SynError {
+        SynError::new(
+            category.span(),
+            format!(
+                "Unknown plural category `{category}`, expected `zero`, `one`, `two`, `few`, `many` or `other`"
+            ),
+        )
+    }
+}
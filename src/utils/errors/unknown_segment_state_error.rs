@@ -0,0 +1,16 @@
+use crate::utils::aliases::SynError;
+use syn::LitStr;
+
+pub struct UnknownSegmentStateError;
+
+impl UnknownSegmentStateError {
+    pub fn new(state: &LitStr) -> SynError {
+        SynError::new(
+            state.span(),
+            format!(
+                "Unknown segment state `{}`, expected `initial`, `translated`, `reviewed` or `final`",
+                state.value()
+            ),
+        )
+    }
+}
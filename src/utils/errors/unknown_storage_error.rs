@@ -0,0 +1,20 @@
+use crate::utils::{aliases::SynError, closest};
+use proc_macro2::Ident;
+
+pub struct UnknownStorageError;
+
+impl UnknownStorageError {
+    pub fn new(storage: &Ident, modes: &[&str]) -> SynError {
+        let suggestion = closest(storage, modes).map_or(".".to_string(), |closest| {
+            format!(", did you mean `{closest}`?")
+        });
+
+        SynError::new(
+            storage.span(),
+            format!(
+                "Unknown storage `{storage}`{suggestion} A custom backend is a type, \
+                 such as `Backend` or `self::backend`"
+            ),
+        )
+    }
+}
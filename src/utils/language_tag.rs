@@ -0,0 +1,107 @@
+use std::fmt::{Display, Formatter, Result};
+
+pub struct LanguageTag {
+    pub language: String,
+    pub script: Option<String>,
+    pub region: Option<String>,
+    pub rest: Vec<String>,
+}
+
+impl LanguageTag {
+    pub fn parse(tag: &str) -> Option<Self> {
+        let mut subtags = tag.split(['-', '_']).peekable();
+
+        let language = subtags
+            .next()
+            .filter(|language| is_language(language))?
+            .to_ascii_lowercase();
+        let script = subtags.next_if(|script| is_script(script)).map(title_case);
+        let region = subtags
+            .next_if(|region| is_region(region))
+            .map(str::to_ascii_uppercase);
+        let rest = subtags
+            .map(|subtag| {
+                is_subtag(subtag)
+                    .then(|| subtag.to_ascii_lowercase())
+                    .ok_or(())
+            })
+            .collect::<std::result::Result<_, _>>()
+            .ok()?;
+
+        Some(Self {
+            language,
+            script,
+            region,
+            rest,
+        })
+    }
+
+    pub fn matching_subtags(tag: &str) -> [String; 3] {
+        Self::parse(tag).map_or([tag.to_string(), String::new(), String::new()], |tag| {
+            [
+                tag.language,
+                tag.script.unwrap_or_default(),
+                tag.region.unwrap_or_default(),
+            ]
+        })
+    }
+
+    pub fn from_variant(variant: &str) -> Option<String> {
+        Self::parse(&split_camel_case(variant)).map(|tag| tag.to_string())
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        formatter.write_str(&self.language)?;
+
+        for subtag in self.script.iter().chain(&self.region).chain(&self.rest) {
+            write!(formatter, "-{subtag}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_language(subtag: &str) -> bool {
+    matches!(subtag.len(), 2..=3 | 5..=8) && subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|byte| byte.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+fn is_subtag(subtag: &str) -> bool {
+    (1..=8).contains(&subtag.len()) && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+}
+
+fn split_camel_case(variant: &str) -> String {
+    let mut subtags = String::new();
+    let mut previous = None::<char>;
+
+    for char in variant.chars() {
+        if previous.is_some_and(|previous| {
+            (previous.is_ascii_lowercase() && char.is_ascii_uppercase())
+                || (previous.is_ascii_alphabetic() && char.is_ascii_digit())
+                || (previous.is_ascii_digit() && char.is_ascii_alphabetic())
+        }) {
+            subtags.push('-');
+        }
+
+        subtags.push(char);
+        previous = Some(char);
+    }
+
+    subtags
+}
+
+fn title_case(subtag: &str) -> String {
+    let (first, rest) = subtag.split_at(1);
+
+    first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+}
@@ -1,14 +1,28 @@
 pub mod aliases;
 mod argument_processor;
+#[cfg(any(
+    feature = "from_ftl",
+    feature = "from_po",
+    feature = "from_json",
+    feature = "from_csv",
+    feature = "from_xliff"
+))]
+pub mod catalog;
+mod closest;
 pub mod errors;
+pub mod language_tag;
 pub mod names;
+pub mod plural_rules;
 mod run_backend;
+pub mod template;
 pub mod typed_parse;
 
 pub use argument_processor::ArgumentProcessor;
+pub use closest::closest;
 pub use names::provider::NamesProvider;
 pub use run_backend::run_backend;
 
+use crate::utils::aliases::SynError;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Path;
@@ -20,3 +34,34 @@ pub fn path_argument(path: Option<Path>) -> TokenStream {
         }
     })
 }
+
+pub fn combine_errors(errors: Vec<SynError>) -> Option<SynError> {
+    errors.into_iter().reduce(|mut combined, error| {
+        combined.combine(error);
+
+        combined
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::combine_errors;
+    use crate::utils::aliases::SynError;
+    use proc_macro2::Span;
+
+    #[test]
+    fn combine() {
+        assert!(combine_errors(Vec::new()).is_none());
+
+        let errors = ["first", "second", "third"]
+            .map(|message| SynError::new(Span::call_site(), message))
+            .into();
+        let messages = combine_errors(errors)
+            .unwrap()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(messages, ["first", "second", "third"]);
+    }
+}
@@ -4,10 +4,25 @@ mod utils;
 use crate::utils::names::utils::short_or_default;
 
 pub const DEFAULT_ENUM_LOCALE: &str = "Locale";
+pub const MOD_LOCALE: &str = "__locale";
+pub const MOD_PRIVATE: &str = "__private";
 pub const MOD_STORAGE: &str = "storage";
+pub const MOD_CONTEXT: &str = "context";
+pub const MOD_PLURAL: &str = "plural";
+pub const MACRO_REGISTER: &str = "register";
 pub const MACRO_EXPRESSION: &str = short_or_default("e", "expression");
 pub const MACRO_EXPRESSIONS: &str = short_or_default("es", "expressions");
 pub const MACRO_LOCALIZE: &str = short_or_default("l", "localize");
 pub const MACRO_EXPRESSION_PART: &str = short_or_default("e_p", "expression_part");
 pub const MACRO_EXPRESSIONS_PART: &str = short_or_default("es_p", "expressions_part");
 pub const MACRO_EXPRESSIONS_FROM_FILES: &str = short_or_default("es_f", "expressions_from_files");
+pub const MACRO_EXPRESSIONS_FROM_FTL: &str = short_or_default("es_ftl", "expressions_from_ftl");
+pub const MACRO_EXPRESSIONS_FROM_PO: &str = short_or_default("es_po", "expressions_from_po");
+pub const MACRO_EXPORT_POT: &str = short_or_default("ex_pot", "export_pot");
+pub const MACRO_EXPRESSIONS_FROM_JSON: &str = short_or_default("es_json", "expressions_from_json");
+pub const MACRO_EXPORT_JSON: &str = short_or_default("ex_json", "export_json");
+pub const MACRO_EXPRESSIONS_FROM_CSV: &str = short_or_default("es_csv", "expressions_from_csv");
+pub const MACRO_EXPORT_CSV: &str = short_or_default("ex_csv", "export_csv");
+pub const MACRO_EXPRESSIONS_FROM_XLIFF: &str =
+    short_or_default("es_xliff", "expressions_from_xliff");
+pub const MACRO_EXPORT_XLIFF: &str = short_or_default("ex_xliff", "export_xliff");
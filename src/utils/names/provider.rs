@@ -32,6 +32,10 @@ impl NamesProvider {
         syn::parse_str(path)
     }
 
+    pub fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
     pub fn get_hashed_name(&self, name: &str) -> Ident {
         Ident::new(&format!("__{}_{}", name, self.hash), Span::call_site())
     }
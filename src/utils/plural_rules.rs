@@ -0,0 +1,340 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+pub const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+type Rules = &'static [(&'static str, &'static str)];
+
+const OTHER: Rules = &[];
+const ONE_I_0_1: Rules = &[("one", "i = 0,1")];
+const ONE_I_1_V_0: Rules = &[("one", "i = 1 and v = 0")];
+const ONE_N_1: Rules = &[("one", "n = 1")];
+const ONE_I_0_OR_N_1: Rules = &[("one", "i = 0 or n = 1")];
+const ONE_N_0_1: Rules = &[("one", "n = 0..1")];
+const MANY_MILLION: &str = "i != 0 and i % 1000000 = 0 and v = 0";
+const ROMANCE_I_1_V_0: Rules = &[("one", "i = 1 and v = 0"), ("many", MANY_MILLION)];
+const ROMANCE_N_1: Rules = &[("one", "n = 1"), ("many", MANY_MILLION)];
+const ROMANCE_I_0_1: Rules = &[("one", "i = 0,1"), ("many", MANY_MILLION)];
+const EAST_SLAVIC: Rules = &[
+    ("one", "v = 0 and i % 10 = 1 and i % 100 != 11"),
+    ("few", "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+    (
+        "many",
+        "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14",
+    ),
+];
+const BELARUSIAN: Rules = &[
+    ("one", "n % 10 = 1 and n % 100 != 11"),
+    ("few", "n % 10 = 2..4 and n % 100 != 12..14"),
+    ("many", "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14"),
+];
+const POLISH: Rules = &[
+    ("one", "i = 1 and v = 0"),
+    ("few", "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+    (
+        "many",
+        "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14",
+    ),
+];
+const CZECH: Rules = &[
+    ("one", "i = 1 and v = 0"),
+    ("few", "i = 2..4 and v = 0"),
+    ("many", "v != 0"),
+];
+const SERBO_CROATIAN: Rules = &[
+    (
+        "one",
+        "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11",
+    ),
+    (
+        "few",
+        "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14",
+    ),
+];
+const SLOVENIAN: Rules = &[
+    ("one", "v = 0 and i % 100 = 1"),
+    ("two", "v = 0 and i % 100 = 2"),
+    ("few", "v = 0 and i % 100 = 3..4 or v != 0"),
+];
+const LITHUANIAN: Rules = &[
+    ("one", "n % 10 = 1 and n % 100 != 11..19"),
+    ("few", "n % 10 = 2..9 and n % 100 != 11..19"),
+    ("many", "f != 0"),
+];
+const LATVIAN: Rules = &[
+    (
+        "zero",
+        "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19",
+    ),
+    (
+        "one",
+        "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1",
+    ),
+];
+const ROMANIAN: Rules = &[
+    ("one", "i = 1 and v = 0"),
+    ("few", "v != 0 or n = 0 or n != 1 and n % 100 = 1..19"),
+];
+const MACEDONIAN: Rules = &[(
+    "one",
+    "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11",
+)];
+const ICELANDIC: Rules = &[(
+    "one",
+    "t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11",
+)];
+const DANISH: Rules = &[("one", "n = 1 or t != 0 and i = 0,1")];
+const SINHALA: Rules = &[("one", "n = 0,1 or i = 0 and f = 1")];
+const FILIPINO: Rules = &[(
+    "one",
+    "v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9",
+)];
+const HEBREW: Rules = &[
+    ("one", "i = 1 and v = 0 or i = 0 and v != 0"),
+    ("two", "i = 2 and v = 0"),
+];
+const ARABIC: Rules = &[
+    ("zero", "n = 0"),
+    ("one", "n = 1"),
+    ("two", "n = 2"),
+    ("few", "n % 100 = 3..10"),
+    ("many", "n % 100 = 11..99"),
+];
+const MALTESE: Rules = &[
+    ("one", "n = 1"),
+    ("two", "n = 2"),
+    ("few", "n = 0 or n % 100 = 3..10"),
+    ("many", "n % 100 = 11..19"),
+];
+const IRISH: Rules = &[
+    ("one", "n = 1"),
+    ("two", "n = 2"),
+    ("few", "n = 3..6"),
+    ("many", "n = 7..10"),
+];
+const SCOTTISH_GAELIC: Rules = &[
+    ("one", "n = 1,11"),
+    ("two", "n = 2,12"),
+    ("few", "n = 3..10,13..19"),
+];
+const WELSH: Rules = &[
+    ("zero", "n = 0"),
+    ("one", "n = 1"),
+    ("two", "n = 2"),
+    ("few", "n = 3"),
+    ("many", "n = 6"),
+];
+
+const LANGUAGES: &[(&[&str], Rules)] = &[
+    (
+        &[
+            "bo", "dz", "id", "ig", "ja", "jv", "km", "ko", "lo", "ms", "my", "sah", "sg", "su",
+            "th", "to", "vi", "wo", "yo", "yue", "zh",
+        ],
+        OTHER,
+    ),
+    (&["ff", "hy", "kab"], ONE_I_0_1),
+    (
+        &[
+            "ast", "de", "en", "et", "fi", "fy", "gl", "ia", "io", "nl", "sc", "sv", "sw", "ur",
+            "yi",
+        ],
+        ONE_I_1_V_0,
+    ),
+    (
+        &[
+            "af", "an", "az", "bg", "ce", "chr", "ckb", "ee", "el", "eo", "eu", "fo", "fur", "gsw",
+            "ha", "haw", "hu", "ka", "kk", "kl", "ks", "ku", "ky", "lb", "lg", "ml", "mn", "mr",
+            "nb", "nd", "ne", "nn", "no", "ny", "om", "or", "os", "ps", "rm", "sd", "sn", "so",
+            "sq", "ss", "st", "ta", "te", "tk", "tn", "tr", "ts", "ug", "uz", "ve", "xh",
+        ],
+        ONE_N_1,
+    ),
+    (
+        &["am", "as", "bn", "fa", "gu", "hi", "kn", "pcm", "zu"],
+        ONE_I_0_OR_N_1,
+    ),
+    (
+        &["ak", "bho", "ln", "mg", "nso", "pa", "ti", "wa"],
+        ONE_N_0_1,
+    ),
+    (&["ca", "it", "vec"], ROMANCE_I_1_V_0),
+    (&["es"], ROMANCE_N_1),
+    (&["fr", "pt"], ROMANCE_I_0_1),
+    (&["ru", "uk"], EAST_SLAVIC),
+    (&["be"], BELARUSIAN),
+    (&["pl"], POLISH),
+    (&["cs", "sk"], CZECH),
+    (&["bs", "hr", "sh", "sr"], SERBO_CROATIAN),
+    (&["sl"], SLOVENIAN),
+    (&["lt"], LITHUANIAN),
+    (&["lv", "prg"], LATVIAN),
+    (&["mo", "ro"], ROMANIAN),
+    (&["mk"], MACEDONIAN),
+    (&["is"], ICELANDIC),
+    (&["da"], DANISH),
+    (&["si"], SINHALA),
+    (&["ceb", "fil", "tl"], FILIPINO),
+    (&["he", "iw"], HEBREW),
+    (&["ar", "ars"], ARABIC),
+    (&["mt"], MALTESE),
+    (&["ga"], IRISH),
+    (&["gd"], SCOTTISH_GAELIC),
+    (&["cy"], WELSH),
+];
+
+pub struct PluralRules {
+    rules: Rules,
+}
+
+impl PluralRules {
+    pub fn new(language: &str) -> Option<Self> {
+        LANGUAGES
+            .iter()
+            .find(|(languages, _)| languages.contains(&language))
+            .map(|(_, rules)| Self { rules })
+    }
+
+    pub fn categories(&self) -> Vec<&'static str> {
+        CATEGORIES
+            .into_iter()
+            .filter(|category| {
+                *category == "other" || self.rules.iter().any(|(rule, _)| rule == category)
+            })
+            .collect()
+    }
+
+    pub fn conditions(&self) -> impl Iterator<Item = (&'static str, TokenStream)> {
+        self.rules
+            .iter()
+            .map(|(category, condition)| (*category, or_condition(condition)))
+    }
+
+    #[cfg(feature = "from_po")]
+    pub fn integer_category(&self, number: u64) -> &'static str {
+        self.rules
+            .iter()
+            .find(|(_, condition)| {
+                condition.split(" or ").any(|condition| {
+                    condition
+                        .split(" and ")
+                        .all(|relation| integer_relation(relation, number))
+                })
+            })
+            .map_or("other", |(category, _)| category)
+    }
+}
+
+pub fn category_ident(category: &str) -> Ident {
+    let (first, rest) = category.split_at(1);
+
+    Ident::new(&(first.to_ascii_uppercase() + rest), Span::call_site())
+}
+
+fn or_condition(condition: &str) -> TokenStream {
+    any(condition.split(" or ").map(and_condition).collect())
+}
+
+fn and_condition(condition: &str) -> TokenStream {
+    all(condition.split(" and ").map(relation).collect())
+}
+
+fn relation(relation: &str) -> TokenStream {
+    let (operand, modulo, ranges, negative) = split_relation(relation);
+    let decimal = operand == "n";
+    let operand = Ident::new(operand, Span::call_site());
+    let value = match modulo {
+        Some(modulo) => {
+            let modulo = number(modulo, decimal);
+
+            quote!((operands.#operand % #modulo))
+        }
+        None => quote!(operands.#operand),
+    };
+
+    let ranges = ranges
+        .split(',')
+        .map(|range| match range.split_once("..") {
+            Some((start, end)) => {
+                let start = number(start, decimal);
+                let end = number(end, decimal);
+
+                if decimal {
+                    quote!(#value % 1.0 == 0.0 && (#start..=#end).contains(&#value))
+                } else {
+                    quote!((#start..=#end).contains(&#value))
+                }
+            }
+            None => {
+                let number = number(range, decimal);
+
+                quote!(#value == #number)
+            }
+        })
+        .collect();
+    let ranges = any(ranges);
+
+    if negative { quote!(!(#ranges)) } else { ranges }
+}
+
+#[cfg(feature = "from_po")]
+fn integer_relation(relation: &str, number: u64) -> bool {
+    let (operand, modulo, ranges, negative) = split_relation(relation);
+    let parse = |number: &str| number.parse::<u64>().unwrap_or_default();
+
+    let mut value = match operand {
+        "n" | "i" => number,
+        _ => 0,
+    };
+    if let Some(modulo) = modulo {
+        value %= parse(modulo).max(1);
+    }
+
+    let contains = ranges.split(',').any(|range| match range.split_once("..") {
+        Some((start, end)) => (parse(start)..=parse(end)).contains(&value),
+        None => value == parse(range),
+    });
+
+    contains != negative
+}
+
+fn split_relation(relation: &str) -> (&str, Option<&str>, &str, bool) {
+    let (expression, ranges, negative) = match relation.split_once(" != ") {
+        Some((expression, ranges)) => (expression, ranges, true),
+        None => {
+            let (expression, ranges) = relation.split_once(" = ").unwrap_or_default();
+
+            (expression, ranges, false)
+        }
+    };
+
+    let (operand, modulo) = expression.split_once(" % ").unzip();
+
+    (operand.unwrap_or(expression), modulo, ranges, negative)
+}
+
+fn any(conditions: Vec<TokenStream>) -> TokenStream {
+    match conditions.as_slice() {
+        [condition] => condition.clone(),
+        _ => quote!(#((#conditions))||*),
+    }
+}
+
+fn all(conditions: Vec<TokenStream>) -> TokenStream {
+    match conditions.as_slice() {
+        [condition] => condition.clone(),
+        _ => quote!(#((#conditions))&&*),
+    }
+}
+
+fn number(number: &str, decimal: bool) -> TokenStream {
+    let number = number.parse::<u64>().unwrap_or_default();
+
+    if decimal {
+        let number = number as f64;
+
+        quote!(#number)
+    } else {
+        quote!(#number)
+    }
+}
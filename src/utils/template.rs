@@ -0,0 +1,97 @@
+use crate::utils::{aliases::SynResult, errors::InvalidTemplateError};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    LitStr,
+    parse::{Parse, ParseStream},
+};
+
+pub struct Template {
+    pub literal: LitStr,
+    pub pieces: Vec<Piece>,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Piece {
+    Text(String),
+    Placeholder(String),
+}
+
+impl Template {
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.pieces.iter().filter_map(|piece| match piece {
+            Piece::Placeholder(placeholder) => Some(placeholder.as_str()),
+            Piece::Text(_) => None,
+        })
+    }
+
+    pub fn write(&self, placeholder: impl Fn(&str) -> TokenStream) -> TokenStream {
+        let pieces = self.pieces.iter().map(|piece| match piece {
+            Piece::Text(text) => quote! {
+                formatter.write_str(#text)?;
+            },
+            Piece::Placeholder(name) => {
+                let value = placeholder(name);
+
+                quote! {
+                    core::fmt::Display::fmt(#value, formatter)?;
+                }
+            }
+        });
+
+        quote! {
+            #(#pieces)*
+
+            core::result::Result::Ok(())
+        }
+    }
+}
+
+impl Parse for Template {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let literal = input.parse::<LitStr>()?;
+        let pieces = parse_pieces(&literal.value()).ok_or(InvalidTemplateError::new(&literal))?;
+
+        Ok(Self { literal, pieces })
+    }
+}
+
+pub fn parse_pieces(template: &str) -> Option<Vec<Piece>> {
+    let mut chars = template.chars().peekable();
+
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.next_if_eq(&'{').is_some() => text.push('{'),
+            '}' if chars.next_if_eq(&'}').is_some() => text.push('}'),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        char if char == '_' || char.is_alphanumeric() => placeholder.push(char),
+                        _ => return None,
+                    }
+                }
+
+                if placeholder.is_empty() {
+                    return None;
+                }
+
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Placeholder(placeholder));
+            }
+            '}' => return None,
+            char => text.push(char),
+        }
+    }
+
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+
+    Some(pieces)
+}
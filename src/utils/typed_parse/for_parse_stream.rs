@@ -5,7 +5,7 @@ use crate::utils::{
     typed_parse::TypedParse,
 };
 use proc_macro2::Ident;
-use syn::{LitBool, LitStr, Path, Token, Type, bracketed, parse::ParseStream};
+use syn::{LitBool, LitStr, Path, Token, braced, bracketed, parse::ParseStream};
 
 impl TypedParse for ParseStream<'_> {
     fn parse_bool(self, name: &str) -> SynResult<bool> {
@@ -22,12 +22,12 @@ impl TypedParse for ParseStream<'_> {
             .value())
     }
 
-    fn parse_ident(self, name: &str) -> SynResult<Ident> {
-        self.parse().map_err(TypeError::map(name, "Ident"))
+    fn parse_lit_str(self, name: &str) -> SynResult<LitStr> {
+        self.parse().map_err(TypeError::map(name, "String"))
     }
 
-    fn parse_type(self, name: &str) -> SynResult<Type> {
-        self.parse().map_err(TypeError::map(name, "Type"))
+    fn parse_ident(self, name: &str) -> SynResult<Ident> {
+        self.parse().map_err(TypeError::map(name, "Ident"))
     }
 
     fn parse_path(self, name: &str) -> SynResult<Path> {
@@ -49,6 +49,33 @@ impl TypedParse for ParseStream<'_> {
             .collect())
     }
 
+    fn parse_map<K, V>(
+        self,
+        name: &str,
+        parse_key: fn(ParseStream) -> SynResult<K>,
+        parse_value: fn(ParseStream) -> SynResult<V>,
+    ) -> SynResult<Vec<(K, V)>> {
+        let content;
+        braced!(content in self);
+
+        let mut map = Vec::new();
+        while !content.is_empty() {
+            let key = parse_key(&content).map_err(TypeError::map(name, "Map"))?;
+            content
+                .parse::<Token![=>]>()
+                .map_err(TypeError::map(name, "Map"))?;
+            let value = parse_value(&content).map_err(TypeError::map(name, "Map"))?;
+
+            map.push((key, value));
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(map)
+    }
+
     fn parse_arguments<T>(self, mut parse_function: T) -> SynResult<()>
     where
         T: FnMut(Ident, &mut ArgumentProcessor) -> SynResult<()>,
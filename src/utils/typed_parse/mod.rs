@@ -2,16 +2,16 @@ mod for_parse_stream;
 
 use crate::utils::{ArgumentProcessor, aliases::SynResult};
 use proc_macro2::Ident;
-use syn::{Path, Type, parse::ParseStream};
+use syn::{LitStr, Path, parse::ParseStream};
 
 pub trait TypedParse {
     fn parse_bool(self, name: &str) -> SynResult<bool>;
 
     fn parse_string(self, name: &str) -> SynResult<String>;
 
-    fn parse_ident(self, name: &str) -> SynResult<Ident>;
+    fn parse_lit_str(self, name: &str) -> SynResult<LitStr>;
 
-    fn parse_type(self, name: &str) -> SynResult<Type>;
+    fn parse_ident(self, name: &str) -> SynResult<Ident>;
 
     fn parse_path(self, name: &str) -> SynResult<Path>;
 
@@ -21,6 +21,13 @@ pub trait TypedParse {
         parse_function: fn(ParseStream) -> SynResult<T>,
     ) -> SynResult<Vec<T>>;
 
+    fn parse_map<K, V>(
+        self,
+        name: &str,
+        parse_key: fn(ParseStream) -> SynResult<K>,
+        parse_value: fn(ParseStream) -> SynResult<V>,
+    ) -> SynResult<Vec<(K, V)>>;
+
     fn parse_arguments<T>(self, parse_function: T) -> SynResult<()>
     where
         T: FnMut(Ident, &mut ArgumentProcessor) -> SynResult<()>;
@@ -7,6 +7,7 @@ fn constants() {
     assert_eq!(Locale::COUNT, 2);
     assert_eq!(Locale::VARIANTS, [Locale::En, Locale::Ru]);
     assert_eq!(Locale::LABELS, ["En", "Ru"]);
+    assert_eq!(Locale::TAGS, ["en", "ru"]);
     assert_eq!(Locale::DEFAULT, Locale::default());
 }
 
@@ -49,6 +50,116 @@ fn from_usize() {
     assert_eq!(Locale::try_from(7).is_err(), true);
 }
 
+#[test]
+fn to_index() {
+    assert_eq!(Locale::Ru.to_index(), 1);
+
+    assert_eq!(Locale::from_index(0), Some(Locale::En));
+    assert_eq!(Locale::from_index(2), None);
+}
+
+mod discriminants {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En = 1,
+        Ru(tag = "ru-RU") = 7 => "Russian",
+        Uk,
+        storage = atomic_u8,
+        path = crate::discriminants,
+    );
+
+    expression!(HELLO => {En: "Hello", Ru: "Привет", Uk: "Привіт"});
+}
+
+#[test]
+fn discriminants() {
+    use crate::discriminants::{HELLO, Locale, localize, storage};
+
+    assert_eq!(Locale::LABELS, ["En", "Russian", "Uk"]);
+    assert_eq!(Locale::TAGS, ["en", "ru-RU", "uk"]);
+
+    assert_eq!(Locale::Ru.to_usize(), 7);
+    assert_eq!(Locale::Uk.to_usize(), 8);
+    assert_eq!(Locale::Ru.to_index(), 1);
+    assert_eq!(Locale::from_usize(7), Some(Locale::Ru));
+    assert_eq!(Locale::from_usize(1), Some(Locale::En));
+    assert_eq!(Locale::from_usize(2), None);
+
+    assert_eq!(localize!(HELLO, Locale::Uk), "Привіт");
+
+    assert_eq!(storage::set_from_usize(7), Ok(()));
+    assert_eq!(storage::get_as_usize(), 7);
+    assert_eq!(localize!(HELLO), "Привет");
+}
+
+mod derived_tags {
+    use localize_it::init_locale;
+
+    init_locale!(En, EnGb, ZhHantTw, Es419, path = crate::derived_tags);
+
+    expression!(
+        APPLES: plural => {
+            En | EnGb: {
+                one: "{n} apple",
+                other: "{n} apples",
+            },
+            Es419: {
+                one: "{n} manzana",
+                many: "{n} de manzanas",
+                other: "{n} manzanas",
+            },
+            ZhHantTw: {
+                other: "{n} 個蘋果",
+            },
+        }
+    );
+}
+
+#[test]
+fn derived_tags() {
+    use crate::derived_tags::{APPLES, Locale, localize};
+
+    assert_eq!(Locale::TAGS, ["en", "en-GB", "zh-Hant-TW", "es-419"]);
+
+    for locale in Locale::iter_variants() {
+        assert_eq!(Locale::from_tag(locale.to_tag()), Some(locale));
+    }
+    assert_eq!(Locale::from_tag("en-gb"), Some(Locale::EnGb));
+    assert_eq!(Locale::from_tag("zh_hant_tw"), Some(Locale::ZhHantTw));
+
+    assert_eq!(
+        localize!(APPLES => [1], Locale::EnGb).to_string(),
+        "1 apple"
+    );
+    assert_eq!(
+        localize!(APPLES => [3], Locale::ZhHantTw).to_string(),
+        "3 個蘋果"
+    );
+}
+
+mod underivable_tags {
+    use localize_it::init_locale;
+
+    init_locale!(
+        English,
+        Ukrainian,
+        Portuguese,
+        X,
+        storage = true,
+        path = crate::underivable_tags
+    );
+}
+
+#[test]
+fn underivable_tags() {
+    use crate::underivable_tags::{Locale, storage};
+
+    assert_eq!(Locale::TAGS, ["english", "ukrainian", "portuguese", "x"]);
+    assert_eq!(Locale::from_tag("english"), Some(Locale::English));
+    assert_eq!(storage::get(), Locale::English);
+}
+
 #[test]
 fn to_str() {
     assert_eq!(Locale::En.to_str(), "En");
@@ -79,3 +190,78 @@ fn from_caseless_str() {
     assert_eq!(Locale::from_caseless_str_or_default("ru"), Locale::Ru);
     assert_eq!(Locale::from_caseless_str_or_default("es"), Locale::En);
 }
+
+mod tagged {
+    use localize_it::init_locale;
+
+    init_locale!(En(tag = "en-us"), Ru, env = true, path = crate::tagged);
+}
+
+#[test]
+fn to_tag() {
+    use crate::tagged::Locale;
+
+    assert_eq!(Locale::En.to_tag(), "en-US");
+    assert_eq!(Locale::Ru.to_tag(), "ru");
+}
+
+#[test]
+fn from_tag() {
+    use crate::tagged::Locale;
+
+    assert_eq!(Locale::from_tag("en-US"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("EN_us"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("en-US-u-ca-gregory"), Some(Locale::En));
+    assert_eq!(Locale::from_tag("RU"), Some(Locale::Ru));
+    assert_eq!(Locale::from_tag("en"), None);
+    assert_eq!(Locale::from_tag("ru-RU"), None);
+    assert_eq!(Locale::from_tag("-"), None);
+
+    assert_eq!(Locale::from_tag_or_default("ru"), Locale::Ru);
+    assert_eq!(Locale::from_tag_or_default("es-ES"), Locale::En);
+}
+
+#[test]
+fn negotiate() {
+    use crate::tagged::Locale;
+
+    assert_eq!(Locale::try_negotiate("ru-RU, en;q=0.8"), Some(Locale::Ru));
+    assert_eq!(
+        Locale::try_negotiate("fr-CH, fr;q=0.9, en-GB;q=0.8"),
+        Some(Locale::En)
+    );
+    assert_eq!(
+        Locale::try_negotiate("en;q=0.5, ru;q=0.7"),
+        Some(Locale::Ru)
+    );
+    assert_eq!(Locale::try_negotiate("ru;q=0, *"), Some(Locale::En));
+    assert_eq!(
+        Locale::try_negotiate("en, *;q=0.5, ru;q=0"),
+        Some(Locale::En)
+    );
+    assert_eq!(Locale::try_negotiate("fr, de;q=2"), None);
+    assert_eq!(Locale::try_negotiate(""), None);
+
+    assert_eq!(Locale::negotiate("RU"), Locale::Ru);
+    assert_eq!(Locale::negotiate("fr"), Locale::En);
+}
+
+#[test]
+fn from_posix() {
+    use crate::tagged::Locale;
+
+    assert_eq!(Locale::from_posix("ru_RU.UTF-8@euro"), Some(Locale::Ru));
+    assert_eq!(Locale::from_posix("en_GB"), Some(Locale::En));
+    assert_eq!(Locale::from_posix("fr_FR:ru:en_US"), Some(Locale::Ru));
+    assert_eq!(Locale::from_posix("C.UTF-8"), None);
+    assert_eq!(Locale::from_posix("POSIX"), None);
+    assert_eq!(Locale::from_posix(""), None);
+}
+
+#[test]
+fn to_posix() {
+    use crate::tagged::Locale;
+
+    assert_eq!(Locale::En.to_posix(), "en_US.UTF-8");
+    assert_eq!(Locale::Ru.to_posix(), "ru.UTF-8");
+}
@@ -0,0 +1,38 @@
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, env = true, path = crate::locale);
+}
+
+use crate::locale::Locale;
+
+// Environment variables are process-wide, so this test lives in its own binary.
+#[test]
+fn from_env() {
+    let set = |values: [&str; 4]| {
+        for (name, value) in ["LC_ALL", "LC_MESSAGES", "LANGUAGE", "LANG"]
+            .into_iter()
+            .zip(values)
+        {
+            unsafe { std::env::set_var(name, value) };
+        }
+    };
+
+    set(["", "", "ru_RU:en", "en_US.UTF-8"]);
+    assert_eq!(Locale::from_env(), Locale::Ru);
+
+    set(["", "de_DE.UTF-8", "ru_RU:en", "en_US.UTF-8"]);
+    assert_eq!(Locale::from_env(), Locale::DEFAULT);
+
+    set(["ru_RU.UTF-8", "de_DE.UTF-8", "", "en_US.UTF-8"]);
+    assert_eq!(Locale::from_env(), Locale::Ru);
+
+    set(["C", "", "ru_RU", "ru_RU.UTF-8"]);
+    assert_eq!(Locale::from_env(), Locale::DEFAULT);
+
+    set(["", "", "", "POSIX"]);
+    assert_eq!(Locale::from_env(), Locale::DEFAULT);
+
+    set(["", "", "", ""]);
+    assert_eq!(Locale::from_env(), Locale::DEFAULT);
+}
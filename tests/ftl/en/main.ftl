@@ -0,0 +1,26 @@
+### Test resource
+
+-brand = Localize It
+    .gender = neuter
+
+# Simple messages
+hello = Hello
+welcome = Welcome to { -brand }, { $user-name }!
+braces = Use {"{"} and {"}"}
+kind = Type: { $type }
+
+multiline =
+    First line
+      Second line
+
+items = { $count ->
+    [one] { $count } item
+   *[other] { $count } items
+}
+cart = Your cart has { $count ->
+    [one] one item
+   *[other] { $count } items
+}.
+
+login = Log in
+    .title = Log in to { -brand }
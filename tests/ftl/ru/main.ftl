@@ -0,0 +1,29 @@
+-brand = Localize It
+    .gender = neuter
+
+hello = Привет
+welcome = Добро пожаловать в { -brand }, { $user-name }!
+braces = Используйте {"{"} и {"}"}
+kind = Тип: { $type }
+
+multiline =
+    Первая строка
+      Вторая строка
+
+items = { $count ->
+    [one] { $count } предмет
+    [few] { $count } предмета
+    [many] { $count } предметов
+   *[other] { $count } предмета
+}
+cart = В корзине { $count ->
+    [one] { $count } товар
+    [few] { $count } товара
+   *[other] { $count } товаров
+}.
+
+login = { -brand.gender ->
+    [masculine] Вошёл
+   *[other] Войти
+}
+    .title = Вход в { -brand }
@@ -0,0 +1,260 @@
+mod fallback {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En,
+        EnGb,
+        Ru,
+        Uk,
+        Kk,
+        fallback = {
+            EnGb => En,
+            Uk => Ru,
+        },
+        path = crate::fallback,
+    );
+
+    expressions!(
+        COLOR => {
+            En: "Color",
+            EnGb: "Colour",
+            Ru: "Цвет",
+        },
+        HELLO => {
+            En: "Hello",
+            Ru: "Привет",
+            Uk: "Привіт",
+        },
+        OK => {
+            En: "OK",
+        },
+    );
+}
+
+use crate::fallback::{COLOR, HELLO, Locale, OK, localize};
+
+#[test]
+fn fallback() {
+    assert_eq!(localize!(COLOR, Locale::EnGb), "Colour");
+    assert_eq!(localize!(COLOR, Locale::Uk), "Цвет");
+    assert_eq!(localize!(COLOR, Locale::Kk), "Color");
+
+    assert_eq!(localize!(HELLO, Locale::EnGb), "Hello");
+    assert_eq!(localize!(HELLO, Locale::Uk), "Привіт");
+
+    for locale in Locale::iter_variants() {
+        assert_eq!(localize!(OK, locale), "OK");
+    }
+}
+
+mod patterns {
+    use localize_it::init_locale;
+
+    init_locale!(En, EnGb, Ru, Uk, path = crate::patterns);
+
+    expressions!(
+        IS_ENGLISH: bool => {
+            En | EnGb: true,
+            _: false,
+        },
+        COLOR => {
+            En: "Color",
+            EnGb: "Colour",
+            Ru | Uk: "Цвет",
+        },
+    );
+}
+
+#[test]
+fn patterns() {
+    use crate::patterns::{COLOR, IS_ENGLISH, Locale, localize};
+
+    assert_eq!(IS_ENGLISH, [true, true, false, false]);
+
+    assert_eq!(localize!(COLOR, Locale::EnGb), "Colour");
+    assert_eq!(localize!(COLOR, Locale::Ru), "Цвет");
+    assert_eq!(localize!(COLOR, Locale::Uk), "Цвет");
+}
+
+mod plural {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, Pl, Ar, Ja, path = crate::plural);
+
+    pub struct PluralCategory(pub &'static str);
+
+    expressions!(
+        ITEMS: plural => {
+            En: {
+                one: "{n} item",
+                other: "{n} items",
+            },
+            Ru: {
+                one: "{n} предмет",
+                few: "{n} предмета",
+                many: "{n} предметов",
+                other: "{n} предмета",
+            },
+            Pl: {
+                one: "{n} przedmiot",
+                few: "{n} przedmioty",
+                many: "{n} przedmiotów",
+                other: "{n} przedmiotu",
+            },
+            Ar: {
+                zero: "لا عناصر",
+                one: "عنصر واحد",
+                two: "عنصران",
+                few: "{n} عناصر",
+                many: "{n} عنصرًا",
+                other: "{n} عنصر",
+            },
+            Ja: {
+                other: "{n} 個",
+            },
+        },
+        DISTANCE: plural(f64) => {
+            En: {
+                one: "{n} mile",
+                other: "{n} miles",
+            },
+            Ru | Pl: {
+                one: "{n} км",
+                few: "{n} км",
+                many: "{n} км",
+                other: "{{{n}}} км",
+            },
+            Ar: {
+                zero: "0",
+                one: "1",
+                two: "2",
+                few: "{n}",
+                many: "{n}",
+                other: "{n}",
+            },
+            _: {
+                other: "{n} マイル",
+            },
+        },
+    );
+}
+
+#[test]
+fn plural() {
+    use crate::plural::{DISTANCE, ITEMS, Locale, localize, plural::PluralCategory};
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(localize!(ITEMS => [0], Locale::En).to_string(), "0 items");
+
+    for (count, expected) in [
+        (1, "1 предмет"),
+        (2, "2 предмета"),
+        (5, "5 предметов"),
+        (11, "11 предметов"),
+        (14, "14 предметов"),
+        (21, "21 предмет"),
+        (112, "112 предметов"),
+        (122, "122 предмета"),
+    ] {
+        assert_eq!(
+            localize!(ITEMS => [count], Locale::Ru).to_string(),
+            expected
+        );
+    }
+
+    assert_eq!(
+        localize!(ITEMS => [22], Locale::Pl).to_string(),
+        "22 przedmioty"
+    );
+    assert_eq!(
+        localize!(ITEMS => [12], Locale::Pl).to_string(),
+        "12 przedmiotów"
+    );
+    assert_eq!(localize!(ITEMS => [0], Locale::Ar).to_string(), "لا عناصر");
+    assert_eq!(localize!(ITEMS => [2], Locale::Ar).to_string(), "عنصران");
+    assert_eq!(localize!(ITEMS => [1], Locale::Ja).to_string(), "1 個");
+
+    assert_eq!(
+        localize!(DISTANCE => [1.0], Locale::En).to_string(),
+        "1 mile"
+    );
+    assert_eq!(
+        localize!(DISTANCE => [1.5], Locale::En).to_string(),
+        "1.5 miles"
+    );
+    assert_eq!(
+        localize!(DISTANCE => [1.5], Locale::Ru).to_string(),
+        "{1.5} км"
+    );
+
+    assert_eq!(Locale::Ru.plural_category(1.5), PluralCategory::Other);
+    assert_eq!(Locale::Ru.plural_category(-3), PluralCategory::Few);
+    assert_eq!(Locale::Pl.plural_category(1u8), PluralCategory::One);
+    assert_eq!(Locale::Ar.plural_category(111), PluralCategory::Many);
+    assert_eq!(Locale::Ja.plural_category(1), PluralCategory::Other);
+    assert_eq!(crate::plural::PluralCategory("own").0, "own");
+
+    let large = 10u128.pow(20);
+    assert_eq!(Locale::Ru.plural_category(large + 1), PluralCategory::One);
+    assert_eq!(Locale::Ru.plural_category(large + 11), PluralCategory::Many);
+    assert_eq!(
+        Locale::Ru.plural_category(-(large as i128) - 2),
+        PluralCategory::Few
+    );
+    assert_eq!(Locale::En.plural_category(large + 1), PluralCategory::Other);
+    assert_eq!(Locale::Ru.plural_category(21usize), PluralCategory::One);
+    assert_eq!(Locale::Ru.plural_category(-21isize), PluralCategory::One);
+}
+
+mod format {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, storage = true, path = crate::format);
+
+    expressions!(
+        HELLO: fmt(name: &str) => {
+            En: "Hello, {name}!",
+            Ru: "Привет, {name}!",
+        },
+        CART: fmt(count: u64, name: &str) => {
+            En: "{name} has {count} items in {{cart}}",
+            Ru: "У {name} в корзине {count} шт.",
+        },
+        EMPTY: fmt() => {
+            _: "Nothing",
+        },
+    );
+}
+
+#[test]
+fn format() {
+    use crate::format::{CART, EMPTY, HELLO, Locale, localize, storage};
+
+    let name = String::from("Ivan");
+    assert_eq!(
+        localize!(HELLO => {name: &name}, Locale::En).to_string(),
+        "Hello, Ivan!"
+    );
+    assert_eq!(
+        localize!(CART => {name: "Ivan", count: 3}, Locale::En).to_string(),
+        "Ivan has 3 items in {cart}"
+    );
+    assert_eq!(localize!(EMPTY => {}, Locale::Ru).to_string(), "Nothing");
+
+    storage::set(Locale::Ru);
+    assert_eq!(
+        localize!(HELLO => {name: "Иван"}).to_string(),
+        "Привет, Иван!"
+    );
+    assert_eq!(
+        localize!(HELLO => {name: "Ivan"}, in Locale::En).to_string(),
+        "Hello, Ivan!"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            localize!(CART => {count: 2, name: "Иван"}).to_string()
+        ),
+        "\"У Иван в корзине 2 шт.\""
+    );
+}
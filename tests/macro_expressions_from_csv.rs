@@ -0,0 +1,101 @@
+#![cfg(feature = "from_csv")]
+
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, path = crate::locale);
+
+    expressions_from_csv!("tests/csv/translations.csv");
+
+    expressions!(
+        /// Shown next to the color picker.
+        COLOR => {
+            En: "Color, { red }",
+            Ru: "Цвет",
+        },
+        APPLES: plural => {
+            En: {one: "{n} apple", other: "{n} apples"},
+            Ru: {one: "{n} яблоко", few: "{n} яблока", many: "{n} яблок", other: "{n} яблока"},
+        },
+    );
+}
+
+use crate::locale::{HELLO, ITEMS, Locale, MULTILINE, WELCOME, export_csv, localize, menu};
+
+#[test]
+fn expressions_from_csv() {
+    assert_eq!(localize!(HELLO, Locale::En), "Hello");
+    assert_eq!(localize!(HELLO, Locale::Ru), "Привет");
+
+    assert_eq!(
+        localize!(WELCOME => {name: "Ivan"}, Locale::En).to_string(),
+        "Welcome, Ivan!"
+    );
+
+    assert_eq!(localize!(menu::OPEN, Locale::Ru), "Открыть");
+    assert_eq!(
+        localize!(menu::SAVE => {file: "a.txt"}, Locale::En).to_string(),
+        "Save \"a.txt\""
+    );
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(
+        localize!(ITEMS => [5], Locale::Ru).to_string(),
+        "5 предметов"
+    );
+
+    assert_eq!(
+        localize!(MULTILINE, Locale::Ru),
+        "Первая строка\nВторая строка"
+    );
+}
+
+#[test]
+fn export_csv() {
+    let mut csv = String::new();
+    export_csv!(
+        &mut csv,
+        [locale::COLOR, menu::SAVE, locale::APPLES, locale::ITEMS],
+    )
+    .unwrap();
+
+    assert_eq!(
+        csv,
+        r#"key,notes,en,ru
+APPLES[one],,{n} apple,{n} яблоко
+APPLES[few],,,{n} яблока
+APPLES[many],,,{n} яблок
+APPLES[other],,{n} apples,{n} яблока
+COLOR,Shown next to the color picker.,"Color, {{ red }}",Цвет
+ITEMS[one],Number of items in the cart,{n} item,{n} предмет
+ITEMS[few],,,{n} предмета
+ITEMS[many],,,{n} предметов
+ITEMS[other],,{n} items,{n} предмета
+menu.SAVE,"Saves the file, shown in the menu","Save ""{file}""",Сохранить «{file}»
+"#
+    );
+}
+
+#[test]
+fn export_all_csv() {
+    let mut all = String::new();
+    export_csv!(&mut all).unwrap();
+
+    let mut listed = String::new();
+    export_csv!(
+        &mut listed,
+        [
+            locale::HELLO,
+            locale::WELCOME,
+            menu::OPEN,
+            menu::SAVE,
+            locale::ITEMS,
+            locale::MULTILINE,
+            locale::COLOR,
+            locale::APPLES,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(all, listed);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "from_ftl")]
+
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, storage = true, path = crate::locale);
+
+    expressions_from_ftl!("tests/ftl/{locale}/main.ftl");
+}
+
+use crate::locale::{
+    BRACES, CART, HELLO, ITEMS, KIND, LOGIN, LOGIN_TITLE, Locale, MULTILINE, WELCOME, localize,
+};
+
+#[test]
+fn expressions_from_ftl() {
+    assert_eq!(localize!(HELLO, Locale::En), "Hello");
+    assert_eq!(localize!(HELLO, Locale::Ru), "Привет");
+
+    assert_eq!(
+        localize!(WELCOME => {user_name: "Ivan"}, Locale::En).to_string(),
+        "Welcome to Localize It, Ivan!"
+    );
+    assert_eq!(
+        localize!(WELCOME => {user_name: "Иван"}, Locale::Ru).to_string(),
+        "Добро пожаловать в Localize It, Иван!"
+    );
+
+    assert_eq!(
+        localize!(KIND => {r#type: "PO"}, Locale::Ru).to_string(),
+        "Тип: PO"
+    );
+
+    assert_eq!(localize!(BRACES, Locale::En), "Use { and }");
+    assert_eq!(
+        localize!(MULTILINE, Locale::En),
+        "First line\n  Second line"
+    );
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(localize!(ITEMS => [5], Locale::En).to_string(), "5 items");
+    assert_eq!(
+        localize!(ITEMS => [22], Locale::Ru).to_string(),
+        "22 предмета"
+    );
+    assert_eq!(
+        localize!(ITEMS => [25], Locale::Ru).to_string(),
+        "25 предметов"
+    );
+
+    assert_eq!(
+        localize!(CART => [1], Locale::En).to_string(),
+        "Your cart has one item."
+    );
+    assert_eq!(
+        localize!(CART => [11], Locale::Ru).to_string(),
+        "В корзине 11 товаров."
+    );
+
+    assert_eq!(localize!(LOGIN, Locale::Ru), "Войти");
+    assert_eq!(localize!(LOGIN_TITLE, Locale::En), "Log in to Localize It");
+
+    locale::storage::set(Locale::Ru);
+    assert_eq!(localize!(LOGIN_TITLE), "Вход в Localize It");
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "from_json")]
+
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, path = crate::locale);
+
+    expressions_from_json!("tests/json/{locale}.json");
+
+    expressions!(
+        COLOR => {
+            En: "Color { red }",
+            Ru: "Цвет",
+        },
+        APPLES: plural => {
+            En: {one: "{n} apple", other: "{n} apples"},
+            Ru: {one: "{n} яблоко", few: "{n} яблока", many: "{n} яблок", other: "{n} яблока"},
+        },
+    );
+}
+
+use crate::locale::{HELLO, ITEMS, Locale, WELCOME, export_json, localize, menu};
+
+#[test]
+fn expressions_from_json() {
+    assert_eq!(localize!(HELLO, Locale::En), "Hello");
+    assert_eq!(localize!(HELLO, Locale::Ru), "Привет");
+
+    assert_eq!(
+        localize!(WELCOME => {name: "Ivan"}, Locale::En).to_string(),
+        "Welcome, Ivan!"
+    );
+
+    assert_eq!(localize!(menu::file::OPEN, Locale::Ru), "Открыть");
+    assert_eq!(
+        localize!(menu::file::SAVE => {file: "a.txt"}, Locale::Ru).to_string(),
+        "Сохранить «a.txt»"
+    );
+    assert_eq!(localize!(menu::QUIT, Locale::En), "Quit");
+    assert_eq!(
+        localize!(menu::type_::OPEN => {r#type: "PO"}, Locale::En).to_string(),
+        "Open PO"
+    );
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(
+        localize!(ITEMS => [5], Locale::Ru).to_string(),
+        "5 предметов"
+    );
+}
+
+#[test]
+fn export_json() {
+    let mut json = String::new();
+    export_json!(
+        &mut json,
+        Locale::Ru,
+        [locale::COLOR, menu::file::OPEN, locale::APPLES, menu::QUIT],
+    )
+    .unwrap();
+
+    assert_eq!(
+        json,
+        r#"{
+  "APPLES": {
+    "one": "{n} яблоко",
+    "few": "{n} яблока",
+    "many": "{n} яблок",
+    "other": "{n} яблока"
+  },
+  "COLOR": "Цвет",
+  "menu": {
+    "QUIT": "Выйти",
+    "file": {
+      "OPEN": "Открыть"
+    }
+  }
+}
+"#
+    );
+
+    json.clear();
+    export_json!(&mut json, Locale::En, [crate::locale::COLOR]).unwrap();
+
+    assert_eq!(json, "{\n  \"COLOR\": \"Color {{ red }}\"\n}\n");
+}
+
+#[test]
+fn export_all_json() {
+    let mut all = String::new();
+    export_json!(&mut all, Locale::Ru).unwrap();
+
+    let mut listed = String::new();
+    export_json!(
+        &mut listed,
+        Locale::Ru,
+        [
+            locale::HELLO,
+            locale::WELCOME,
+            menu::file::OPEN,
+            menu::file::SAVE,
+            menu::type_::OPEN,
+            menu::QUIT,
+            locale::ITEMS,
+            locale::COLOR,
+            locale::APPLES,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(all, listed);
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "from_po")]
+
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, fallback = {}, path = crate::locale);
+
+    expressions_from_po!("tests/po/{locale}.po");
+
+    expressions!(
+        COLOR => {
+            En: "Color { red }",
+            Ru: "Цвет",
+        },
+        /// Greets the user by name.
+        GREETING: fmt(name: &str) => {
+            En: "Hi, {name}!",
+            Ru: "Привет, {name}!",
+        },
+        APPLES: plural => {
+            En: {one: "{n} apple", other: "{n} apples"},
+            Ru: {one: "{n} яблоко", few: "{n} яблока", many: "{n} яблок", other: "{n} яблока"},
+        },
+        IS_ENGLISH: bool => {
+            En: true,
+            Ru: false,
+        },
+    );
+
+    pub mod errors {
+        use crate::locale::expression;
+
+        expression!(NOT_FOUND => {
+            En: "Not found",
+            Ru: "Не найдено",
+        });
+    }
+}
+
+use crate::locale::{ITEMS, Locale, MULTILINE, WELCOME, export_pot, localize, menu};
+
+#[test]
+fn expressions_from_po() {
+    assert_eq!(localize!(locale::HELLO, Locale::En), "Hello");
+    assert_eq!(localize!(locale::HELLO, Locale::Ru), "Привет");
+
+    assert_eq!(
+        localize!(WELCOME => {name: "Иван"}, Locale::Ru).to_string(),
+        "Добро пожаловать, Иван!"
+    );
+
+    assert_eq!(localize!(menu::OPEN, Locale::En), "Open…");
+    assert_eq!(localize!(menu::OPEN, Locale::Ru), "Open…");
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(localize!(ITEMS => [2], Locale::En).to_string(), "2 items");
+    assert_eq!(
+        localize!(ITEMS => [3], Locale::Ru).to_string(),
+        "3 предмета"
+    );
+    assert_eq!(
+        localize!(ITEMS => [5], Locale::Ru).to_string(),
+        "5 предметов"
+    );
+
+    assert_eq!(
+        localize!(MULTILINE, Locale::En),
+        "First line\nSecond \"line\""
+    );
+    assert_eq!(
+        localize!(MULTILINE, Locale::Ru),
+        "Первая строка\nВторая \"строка\""
+    );
+}
+
+#[test]
+fn export_pot() {
+    let mut pot = String::new();
+    export_pot!(
+        &mut pot,
+        [
+            locale::COLOR,
+            locale::GREETING,
+            crate::locale::APPLES,
+            menu::OPEN,
+            locale::errors::NOT_FOUND,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        pot,
+        r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+msgctxt "APPLES"
+msgid "{n} apple"
+msgid_plural "{n} apples"
+msgstr[0] ""
+msgstr[1] ""
+
+msgctxt "COLOR"
+msgid "Color {{ red }}"
+msgstr ""
+
+#. Greets the user by name.
+msgctxt "GREETING"
+msgid "Hi, {name}!"
+msgstr ""
+
+msgctxt "errors.NOT_FOUND"
+msgid "Not found"
+msgstr ""
+
+#. Shown in the file menu.
+msgctxt "menu.OPEN"
+msgid "Open…"
+msgstr ""
+"#
+    );
+}
+
+#[test]
+fn export_all_pot() {
+    let mut all = String::new();
+    export_pot!(&mut all).unwrap();
+
+    let mut listed = String::new();
+    export_pot!(
+        &mut listed,
+        [
+            locale::HELLO,
+            locale::WELCOME,
+            menu::OPEN,
+            locale::ITEMS,
+            locale::MULTILINE,
+            locale::COLOR,
+            locale::GREETING,
+            locale::APPLES,
+            locale::errors::NOT_FOUND,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(all, listed);
+}
+
+mod plural_forms {
+    use localize_it::init_locale;
+
+    init_locale!(En, Fr, Lv, path = crate::plural_forms);
+
+    expressions_from_po!("tests/po/plural/{locale}.po");
+}
+
+#[test]
+fn plural_forms() {
+    use crate::plural_forms::{ITEMS, Locale, localize};
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(localize!(ITEMS => [0], Locale::En).to_string(), "0 items");
+
+    assert_eq!(localize!(ITEMS => [0], Locale::Fr).to_string(), "0 élément");
+    assert_eq!(
+        localize!(ITEMS => [2], Locale::Fr).to_string(),
+        "2 éléments"
+    );
+    assert_eq!(
+        localize!(ITEMS => [1_000_000], Locale::Fr).to_string(),
+        "1000000 éléments"
+    );
+
+    for (count, expected) in [
+        (1, "1 vienums"),
+        (21, "21 vienums"),
+        (2, "2 vienumi"),
+        (0, "0 vienumu"),
+        (10, "10 vienumu"),
+        (11, "11 vienumu"),
+    ] {
+        assert_eq!(
+            localize!(ITEMS => [count], Locale::Lv).to_string(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "from_xliff")]
+
+mod locale {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, fallback = {}, path = crate::locale);
+
+    expressions_from_xliff!("tests/xliff/{locale}.xlf");
+
+    expressions!(
+        /// Shown next to the color picker.
+        #[state(Ru = "reviewed")]
+        COLOR => {
+            En: "Color & { red }",
+            Ru: "Цвет",
+        },
+    );
+}
+
+use crate::locale::{HELLO, ITEMS, Locale, export_xliff, localize, menu};
+
+#[test]
+fn expressions_from_xliff() {
+    assert_eq!(localize!(HELLO, Locale::En), "Hello");
+    assert_eq!(localize!(HELLO, Locale::Ru), "Привет");
+
+    assert_eq!(
+        localize!(menu::SAVE => {file: "a.txt"}, Locale::En).to_string(),
+        "Save <a.txt>"
+    );
+    assert_eq!(
+        localize!(menu::SAVE => {file: "a.txt"}, Locale::Ru).to_string(),
+        "Сохранить «a.txt»"
+    );
+    assert_eq!(localize!(menu::QUIT, Locale::Ru), "Quit");
+
+    assert_eq!(localize!(ITEMS => [1], Locale::En).to_string(), "1 item");
+    assert_eq!(
+        localize!(ITEMS => [5], Locale::Ru).to_string(),
+        "5 предметов"
+    );
+}
+
+#[test]
+fn export_xliff() {
+    let mut xliff = String::new();
+    export_xliff!(
+        &mut xliff,
+        Locale::Ru,
+        [locale::HELLO, menu::SAVE, locale::COLOR, locale::ITEMS],
+    )
+    .unwrap();
+
+    assert_eq!(
+        xliff,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="ru">
+  <file id="messages">
+    <unit id="COLOR">
+      <notes>
+        <note>Shown next to the color picker.</note>
+      </notes>
+      <segment state="reviewed">
+        <source>Color &amp; { red }</source>
+        <target>Цвет</target>
+      </segment>
+    </unit>
+    <unit id="HELLO">
+      <notes>
+        <note>Greeting on the start screen</note>
+      </notes>
+      <segment state="final">
+        <source>Hello</source>
+        <target>Привет</target>
+      </segment>
+    </unit>
+    <group id="ITEMS" type="localize_it:plural">
+      <notes>
+        <note>Number of items in the cart</note>
+      </notes>
+      <unit id="ITEMS.one">
+        <segment state="translated">
+          <source><ph id="n" disp="{n}"/> item</source>
+          <target><ph id="n" disp="{n}"/> предмет</target>
+        </segment>
+      </unit>
+      <unit id="ITEMS.few">
+        <segment state="translated">
+          <source><ph id="n" disp="{n}"/> items</source>
+          <target><ph id="n" disp="{n}"/> предмета</target>
+        </segment>
+      </unit>
+      <unit id="ITEMS.many">
+        <segment state="translated">
+          <source><ph id="n" disp="{n}"/> items</source>
+          <target><ph id="n" disp="{n}"/> предметов</target>
+        </segment>
+      </unit>
+      <unit id="ITEMS.other">
+        <segment state="translated">
+          <source><ph id="n" disp="{n}"/> items</source>
+          <target><ph id="n" disp="{n}"/> предмета</target>
+        </segment>
+      </unit>
+    </group>
+    <unit id="menu.SAVE">
+      <segment state="reviewed">
+        <source>Save &lt;<ph id="file" disp="{file}"/>&gt;</source>
+        <target>Сохранить «<ph id="file" disp="{file}"/>»</target>
+      </segment>
+    </unit>
+  </file>
+</xliff>
+"#
+    );
+}
+
+#[test]
+fn export_all_xliff() {
+    let mut all = String::new();
+    export_xliff!(&mut all, Locale::Ru).unwrap();
+
+    let mut listed = String::new();
+    export_xliff!(
+        &mut listed,
+        Locale::Ru,
+        [
+            locale::HELLO,
+            menu::SAVE,
+            menu::QUIT,
+            locale::ITEMS,
+            locale::COLOR,
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(all, listed);
+}
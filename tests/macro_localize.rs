@@ -1,6 +1,8 @@
 mod locale;
 
 use crate::locale::{
+    Locale,
+    context::{CurrentLocale, LocaleContext},
     expressions::{TEST, TEST_CALLABLE},
     localize,
 };
@@ -15,3 +17,69 @@ fn localize() {
         TEST_CALLABLE[0](argument)
     );
 }
+
+#[test]
+fn localize_in_context() {
+    struct Request {
+        locale: Locale,
+    }
+
+    impl CurrentLocale for Request {
+        fn current_locale(&self) -> Locale {
+            self.locale
+        }
+    }
+
+    let request = Request { locale: Locale::Ru };
+    assert_eq!(localize!(TEST, in request), "Тест");
+    assert_eq!(localize!(TEST, in &request), "Тест");
+    assert_eq!(localize!(TEST_CALLABLE => ["Test"], in request), "Test");
+
+    let context = LocaleContext::new(Locale::Ru);
+    assert_eq!(localize!(TEST, in context), "Тест");
+    assert_eq!(localize!(TEST, in LocaleContext::default()), "Test");
+    assert_eq!(localize!(TEST, in Locale::Ru), "Тест");
+}
+
+mod own_context {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, path = crate::own_context);
+
+    expression!(TEST => {
+        En: "Test",
+        Ru: "Тест",
+    });
+
+    pub struct LocaleContext(pub Locale);
+
+    pub trait CurrentLocale {
+        fn current_locale(&self) -> Locale;
+    }
+
+    impl CurrentLocale for LocaleContext {
+        fn current_locale(&self) -> Locale {
+            self.0
+        }
+    }
+
+    impl context::CurrentLocale for LocaleContext {
+        fn current_locale(&self) -> Locale {
+            CurrentLocale::current_locale(self)
+        }
+    }
+}
+
+#[test]
+fn localize_in_own_context() {
+    use crate::own_context::{LocaleContext, TEST, context, localize};
+
+    assert_eq!(
+        localize!(TEST, in LocaleContext(own_context::Locale::Ru)),
+        "Тест"
+    );
+    assert_eq!(
+        localize!(TEST, in context::LocaleContext::new(own_context::Locale::Ru)),
+        "Тест"
+    );
+}
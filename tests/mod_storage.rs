@@ -53,8 +53,444 @@ fn set_caseless_str() {
     assert_eq!(storage::get_as_str(), "En");
 }
 
+#[test]
+fn set_tag() {
+    assert_eq!(storage::set_from_tag("ru"), Ok(()));
+    assert_eq!(storage::get(), Locale::Ru);
+
+    assert_eq!(storage::set_from_tag("es").is_err(), true);
+    assert_eq!(storage::get(), Locale::Ru);
+
+    storage::set_from_tag_or_default("en_US");
+    assert_eq!(storage::get(), Locale::En);
+}
+
+#[test]
+fn set_accept_language() {
+    assert_eq!(storage::set_from_accept_language("ru-RU, en;q=0.5"), Ok(()));
+    assert_eq!(storage::get(), Locale::Ru);
+
+    assert_eq!(storage::set_from_accept_language("es").is_err(), true);
+    assert_eq!(storage::get(), Locale::Ru);
+
+    storage::set_from_accept_language_or_default("es");
+    assert_eq!(storage::get(), Locale::En);
+}
+
 #[test]
 fn reset() {
     storage::reset();
     assert_eq!(storage::get(), Locale::En);
 }
+
+mod thread_local_storage {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En(tag = "en-US"),
+        Ru,
+        storage = thread_local,
+        persist = true,
+        path = crate::thread_local_storage,
+    );
+
+    expression!(HELLO => {En: "Hello", Ru: "Привет"});
+}
+
+#[test]
+fn thread_local_set_and_get() {
+    use crate::thread_local_storage::{HELLO, Locale, localize, storage};
+
+    storage::set(Locale::Ru);
+    assert_eq!(storage::get(), Locale::Ru);
+    assert_eq!(localize!(HELLO), "Привет");
+}
+
+#[test]
+fn thread_local_spawn_with_current() {
+    use crate::thread_local_storage::{HELLO, Locale, localize, storage};
+
+    storage::set(Locale::Ru);
+    let hello = storage::spawn_with_current(|| localize!(HELLO))
+        .join()
+        .unwrap();
+    assert_eq!(hello, "Привет");
+}
+
+#[test]
+fn thread_local_default() {
+    use crate::thread_local_storage::{Locale, storage};
+
+    assert_eq!(storage::get_default(), Locale::En);
+
+    storage::set_default(Locale::Ru);
+    let other = std::thread::spawn(storage::get).join().unwrap();
+    assert_eq!(other, Locale::Ru);
+
+    storage::set(Locale::En);
+    storage::reset();
+    assert_eq!(storage::get(), Locale::Ru);
+
+    storage::set_default(Locale::En);
+}
+
+#[test]
+fn thread_local_scoped() {
+    use crate::thread_local_storage::{HELLO, Locale, localize, storage};
+
+    storage::set(Locale::En);
+    assert_eq!(storage::replace(Locale::Ru), Locale::En);
+    assert_eq!(storage::replace(Locale::En), Locale::Ru);
+
+    {
+        let _guard = storage::scoped(Locale::Ru);
+        assert_eq!(localize!(HELLO), "Привет");
+    }
+    assert_eq!(storage::get(), Locale::En);
+
+    assert_eq!(storage::with(Locale::Ru, || localize!(HELLO)), "Привет");
+    assert_eq!(storage::get(), Locale::En);
+
+    let result = std::panic::catch_unwind(|| storage::with(Locale::Ru, || panic!()));
+    assert_eq!(result.is_err(), true);
+    assert_eq!(storage::get(), Locale::En);
+}
+
+#[test]
+fn thread_local_instrument() {
+    use crate::thread_local_storage::{HELLO, Locale, localize, storage};
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    async fn yield_now() {
+        let mut yielded = false;
+        std::future::poll_fn(|_| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    async fn greet() -> [&'static str; 2] {
+        let before = localize!(HELLO);
+        yield_now().await;
+        [before, localize!(HELLO)]
+    }
+
+    storage::set(Locale::En);
+    let mut context = Context::from_waker(Waker::noop());
+    let mut russian = pin!(storage::instrument(greet(), Locale::Ru));
+    let mut english = pin!(storage::instrument(greet(), Locale::En));
+
+    assert_eq!(russian.as_mut().poll(&mut context), Poll::Pending);
+    assert_eq!(english.as_mut().poll(&mut context), Poll::Pending);
+    assert_eq!(storage::get(), Locale::En);
+
+    storage::set(Locale::Ru);
+    assert_eq!(
+        english.as_mut().poll(&mut context),
+        Poll::Ready(["Hello", "Hello"])
+    );
+    assert_eq!(
+        russian.as_mut().poll(&mut context),
+        Poll::Ready(["Привет", "Привет"])
+    );
+    assert_eq!(storage::get(), Locale::Ru);
+}
+
+mod subscribed_storage {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En,
+        Ru,
+        storage = thread_local,
+        path = crate::subscribed_storage
+    );
+}
+
+#[test]
+fn subscribe_and_generation() {
+    use crate::subscribed_storage::{Locale, storage};
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::Mutex,
+        task::{Context, Poll, Waker},
+    };
+
+    static CHANGES: Mutex<Vec<(Locale, Locale)>> = Mutex::new(Vec::new());
+
+    fn listener(old: Locale, new: Locale) {
+        CHANGES.lock().unwrap().push((old, new));
+    }
+
+    for _ in 0..storage::MAX_LISTENERS {
+        assert_eq!(storage::subscribe(listener), Ok(()));
+    }
+    assert_eq!(storage::subscribe(listener).is_err(), true);
+
+    let generation = storage::generation();
+    storage::set(Locale::Ru);
+    storage::set(Locale::Ru);
+    assert_eq!(storage::generation(), generation + 1);
+
+    let changes = CHANGES.lock().unwrap().split_off(0);
+    assert_eq!(changes, [(Locale::En, Locale::Ru); storage::MAX_LISTENERS]);
+
+    let generation = storage::generation();
+    storage::with(Locale::En, || {});
+    storage::with(Locale::Ru, || {});
+    let future = pin!(storage::instrument(async {}, Locale::En));
+    assert_eq!(
+        future.poll(&mut Context::from_waker(Waker::noop())),
+        Poll::Ready(())
+    );
+    assert_eq!(storage::generation(), generation);
+    assert_eq!(CHANGES.lock().unwrap().len(), 0);
+
+    storage::reset();
+    assert_eq!(storage::get(), Locale::En);
+    assert_eq!(storage::generation(), generation + 1);
+    assert_eq!(CHANGES.lock().unwrap().len(), storage::MAX_LISTENERS);
+}
+
+#[test]
+fn thread_local_persist() {
+    use crate::thread_local_storage::{Locale, storage};
+
+    let directory = std::env::temp_dir().join(format!("localize_it_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("locale");
+
+    storage::set(Locale::Ru);
+    storage::load_from(&path);
+    assert_eq!(storage::get(), Locale::En);
+
+    storage::set(Locale::Ru);
+    storage::save_to(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "ru");
+    assert_eq!(
+        std::fs::exists(directory.join("locale.tmp")).unwrap(),
+        false
+    );
+
+    storage::set(Locale::En);
+    storage::load_from(&path);
+    assert_eq!(storage::get(), Locale::Ru);
+
+    std::fs::write(&path, [0xff, 0xfe]).unwrap();
+    storage::load_from(&path);
+    assert_eq!(storage::get(), Locale::En);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+mod derived_tag_storage {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En,
+        EnGb,
+        storage = thread_local,
+        persist = true,
+        path = crate::derived_tag_storage,
+    );
+}
+
+#[test]
+fn derived_tag_persist() {
+    use crate::derived_tag_storage::{Locale, storage};
+
+    let directory =
+        std::env::temp_dir().join(format!("localize_it_derived_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("locale");
+
+    storage::set(Locale::EnGb);
+    storage::save_to(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "en-GB");
+
+    storage::set(Locale::En);
+    storage::load_from(&path);
+    assert_eq!(storage::get(), Locale::EnGb);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+mod atomic_u8_storage {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, storage = atomic_u8, path = crate::atomic_u8_storage);
+}
+
+#[test]
+fn atomic_u8_set_and_get() {
+    use crate::atomic_u8_storage::{Locale, storage};
+
+    storage::set(Locale::Ru);
+    assert_eq!(storage::get_as_usize(), 1);
+    assert_eq!(storage::replace(Locale::En), Locale::Ru);
+
+    storage::set_from_str_or_default("Ru");
+    storage::reset();
+    assert_eq!(storage::get(), Locale::En);
+}
+
+mod single_core_storage {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En,
+        Ru,
+        storage = unsafe_single_core,
+        path = crate::single_core_storage,
+    );
+}
+
+#[test]
+fn single_core_set_and_get() {
+    use crate::single_core_storage::{Locale, storage};
+
+    assert_eq!(
+        storage::subscribe(|_, new| assert_eq!(new, Locale::Ru)),
+        Ok(())
+    );
+
+    storage::set(Locale::Ru);
+    assert_eq!(storage::get(), Locale::Ru);
+    assert_eq!(storage::generation(), 1);
+
+    storage::with(Locale::En, || assert_eq!(storage::get(), Locale::En));
+    assert_eq!(storage::get(), Locale::Ru);
+    assert_eq!(storage::generation(), 1);
+}
+
+mod custom_storage {
+    use localize_it::init_locale;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    init_locale!(En, Ru, storage = TestStorage, path = crate::custom_storage);
+
+    pub static STORED: AtomicUsize = AtomicUsize::new(1);
+
+    pub struct TestStorage;
+
+    impl storage::LocaleStorage for TestStorage {
+        fn load() -> usize {
+            STORED.load(Ordering::Relaxed)
+        }
+
+        fn store(value: usize) {
+            STORED.store(value, Ordering::Relaxed);
+        }
+    }
+}
+
+#[test]
+fn custom_set_and_get() {
+    use crate::custom_storage::{Locale, STORED, storage};
+    use std::sync::atomic::Ordering;
+
+    assert_eq!(storage::get(), Locale::Ru);
+
+    assert_eq!(storage::replace(Locale::En), Locale::Ru);
+    assert_eq!(STORED.load(Ordering::Relaxed), 0);
+
+    STORED.store(1, Ordering::Relaxed);
+    assert_eq!(storage::get_as_str(), "Ru");
+
+    storage::reset();
+    assert_eq!(STORED.load(Ordering::Relaxed), 0);
+}
+
+mod test_support_storage {
+    use localize_it::init_locale;
+
+    init_locale!(
+        En,
+        Ru,
+        storage = true,
+        test_support = true,
+        path = crate::test_support_storage
+    );
+}
+
+#[test]
+fn test_guard() {
+    use crate::test_support_storage::{Locale, storage};
+
+    storage::set(Locale::En);
+
+    {
+        let _guard = storage::test_guard(Locale::Ru);
+        assert_eq!(storage::get(), Locale::Ru);
+    }
+    assert_eq!(storage::get(), Locale::En);
+}
+
+#[test]
+fn test_guard_serializes_threads() {
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use crate::test_support_storage::{Locale, storage};
+
+    static HELD: AtomicBool = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        for locale in [Locale::En, Locale::Ru] {
+            scope.spawn(move || {
+                let _guard = storage::test_guard(locale);
+                assert!(!HELD.swap(true, Ordering::SeqCst));
+
+                thread::sleep(Duration::from_millis(20));
+                assert_eq!(storage::get(), locale);
+
+                HELD.store(false, Ordering::SeqCst);
+            });
+        }
+    });
+}
+
+mod named_storage {
+    use localize_it::init_locale;
+
+    init_locale!(En, Ru, storage = [ui, logs], path = crate::named_storage);
+
+    expressions!(
+        HELLO => {En: "Hello", Ru: "Привет"},
+        GREETING: fmt(name: &str) => {En: "Hello, {name}!", Ru: "Привет, {name}!"},
+    );
+}
+
+#[test]
+fn named_set_and_get() {
+    use crate::named_storage::{GREETING, HELLO, Locale, localize, storage};
+
+    storage::ui::set(Locale::Ru);
+    storage::logs::set(Locale::En);
+    assert_eq!(storage::ui::get(), Locale::Ru);
+    assert_eq!(storage::logs::get(), Locale::En);
+
+    assert_eq!(localize!(HELLO), "Привет");
+    assert_eq!(localize!(HELLO, @ui), "Привет");
+    assert_eq!(localize!(HELLO, @logs), "Hello");
+    assert_eq!(
+        localize!(GREETING => {name: "Ivan"}, @logs).to_string(),
+        "Hello, Ivan!"
+    );
+
+    storage::ui::reset();
+    assert_eq!(storage::ui::get(), Locale::En);
+}